@@ -11,7 +11,7 @@ fn main() {
 
     // Generate annotation documentation
     writeln!(f, "// Auto-generated documentation - DO NOT EDIT").unwrap();
-    writeln!(f, "").unwrap();
+    writeln!(f).unwrap();
 
     // Scan annotations directory
     writeln!(
@@ -83,7 +83,7 @@ fn main() {
     .unwrap();
     writeln!(f, "    }}").unwrap();
     writeln!(f, "}}").unwrap();
-    writeln!(f, "").unwrap();
+    writeln!(f).unwrap();
 
     // Tell Cargo to rerun if the sections directory changes
     println!("cargo:rerun-if-changed={}", sections_dir.display());
@@ -121,7 +121,7 @@ fn main() {
     writeln!(f, "        _ => \"Unknown annotation\",").unwrap();
     writeln!(f, "    }}").unwrap();
     writeln!(f, "}}").unwrap();
-    writeln!(f, "").unwrap();
+    writeln!(f).unwrap();
 
     // Generate status code documentation
     writeln!(
@@ -839,7 +839,15 @@ fn find_section_insertion_point(
     let effective_end = find_effective_doc_end(content, doc_start, doc_end);
 
     // Define section order
-    let section_order = ["Path Parameters", "Responses", "Examples", "Metadata"];
+    let section_order = [
+        "Path Parameters",
+        "Query Parameters",
+        "Headers",
+        "Request Body",
+        "Responses",
+        "Examples",
+        "Metadata",
+    ];
     let Some(target_index) = section_order.iter().position(|&s| s == section_name) else {
         return effective_end;
     };
@@ -1169,7 +1177,7 @@ fn create_smart_rest_responses_action(
 
     let mut changes = std::collections::HashMap::new();
 
-    let responses = vec![
+    let responses = [
         "200: Json<T> - Success",
         "400: Json<Error> - Bad request",
         "404: Json<Error> - Not found",
@@ -97,7 +97,7 @@ pub fn get_completions(content: &str, position: Position) -> Vec<CompletionItem>
                 || after_doc
                     .chars()
                     .next()
-                    .map(|c| c.is_digit(10))
+                    .map(|c| c.is_ascii_digit())
                     .unwrap_or(false)
             {
                 return get_response_line_completions();
@@ -109,12 +109,17 @@ pub fn get_completions(content: &str, position: Position) -> Vec<CompletionItem>
                 || after_doc
                     .chars()
                     .next()
-                    .map(|c| c.is_digit(10))
+                    .map(|c| c.is_ascii_digit())
                     .unwrap_or(false)
             {
                 return get_example_line_completions();
             }
         }
+        SectionContext::QueryParametersSection
+        | SectionContext::HeadersSection
+        | SectionContext::RequestBodySection => {
+            // Names and descriptions are free-form, nothing to complete
+        }
         SectionContext::MetadataSection => {
             // In # Metadata section, only allow @ annotations
             if after_doc.starts_with('@') {
@@ -139,6 +144,9 @@ enum SectionContext {
     ExamplesSection,
     MetadataSection,
     PathParametersSection,
+    QueryParametersSection,
+    HeadersSection,
+    RequestBodySection,
     None,
 }
 
@@ -163,6 +171,12 @@ fn detect_section_context(lines: &[&str], current_line: usize) -> SectionContext
             return SectionContext::MetadataSection;
         } else if content == "# Path Parameters" {
             return SectionContext::PathParametersSection;
+        } else if content == "# Query Parameters" {
+            return SectionContext::QueryParametersSection;
+        } else if content == "# Headers" {
+            return SectionContext::HeadersSection;
+        } else if content == "# Request Body" {
+            return SectionContext::RequestBodySection;
         }
 
         // Check for #[rovo] attribute - we've gone too far
@@ -183,6 +197,18 @@ fn get_section_completions(typed: &str, _context: &SectionContext) -> Vec<Comple
             "# Path Parameters",
             "# Path Parameters\n///\n/// ${1:param_name}: ${2:description}",
         ),
+        (
+            "# Query Parameters",
+            "# Query Parameters\n///\n/// ${1:param_name}: ${2:description}",
+        ),
+        (
+            "# Headers",
+            "# Headers\n///\n/// ${1:X-Header-Name}: ${2:String} - ${3:description}",
+        ),
+        (
+            "# Request Body",
+            "# Request Body\n///\n/// ${1:description}\n/// Example: ${2:expression}",
+        ),
         (
             "# Responses",
             "# Responses\n///\n/// ${1:200}: ${2:Json<T>} - ${3:description}",
@@ -777,10 +803,10 @@ mod tests {
 
     #[test]
     fn test_section_completion_has_snippet() {
-        let content = "/// # R";
+        let content = "/// # Res";
         let position = Position {
             line: 0,
-            character: 7,
+            character: 9,
         };
         let completions = get_completions(content, position);
 
@@ -1058,16 +1084,19 @@ mod tests {
     #[test]
     fn test_section_completions_all_sections() {
         let completions = get_section_completions("# ", &SectionContext::None);
-        assert_eq!(completions.len(), 4);
+        assert_eq!(completions.len(), 7);
         assert!(completions.iter().any(|c| c.label == "# Responses"));
         assert!(completions.iter().any(|c| c.label == "# Examples"));
         assert!(completions.iter().any(|c| c.label == "# Metadata"));
         assert!(completions.iter().any(|c| c.label == "# Path Parameters"));
+        assert!(completions.iter().any(|c| c.label == "# Query Parameters"));
+        assert!(completions.iter().any(|c| c.label == "# Headers"));
+        assert!(completions.iter().any(|c| c.label == "# Request Body"));
     }
 
     #[test]
     fn test_section_completions_filter() {
-        let completions = get_section_completions("# R", &SectionContext::None);
+        let completions = get_section_completions("# Res", &SectionContext::None);
         // Should only match "# Responses"
         assert_eq!(completions.len(), 1);
         assert_eq!(completions[0].label, "# Responses");
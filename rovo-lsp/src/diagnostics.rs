@@ -1,4 +1,4 @@
-use crate::parser::AnnotationKind;
+use crate::parser::{is_rovo_attribute, AnnotationKind};
 
 /// Severity level for diagnostic messages
 #[derive(Debug, Clone, PartialEq)]
@@ -80,7 +80,7 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
         match ann.kind {
             AnnotationKind::Response => {
                 if let Some(status) = ann.status {
-                    if status < 100 || status > 599 {
+                    if !(100..=599).contains(&status) {
                         // Find the position of the status code in the line
                         let (char_start, char_end) = if ann.line < lines.len() {
                             let line = lines[ann.line];
@@ -119,8 +119,8 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
                         let mut end_line = start_line;
 
                         // Scan forward to find the end of the example
-                        for i in (start_line + 1)..lines.len() {
-                            let line = lines[i].trim();
+                        for (i, line) in lines.iter().enumerate().skip(start_line + 1) {
+                            let line = line.trim();
                             if line.starts_with("///") {
                                 let content = line.trim_start_matches("///").trim();
 
@@ -192,7 +192,7 @@ fn check_undocumented_path_params(_content: &str, lines: &[&str]) -> Vec<Diagnos
 
     // Find all #[rovo] blocks
     for (rovo_line, line) in lines.iter().enumerate() {
-        if !line.contains("#[rovo]") {
+        if !is_rovo_attribute(line) {
             continue;
         }
 
@@ -161,6 +161,9 @@ fn get_annotation_at_position(line: &str, char_idx: usize) -> Option<String> {
         if char_idx >= section_start && char_idx <= section_end {
             match section_name {
                 "Path Parameters" => return Some("section:path-parameters".to_string()),
+                "Query Parameters" => return Some("section:query-parameters".to_string()),
+                "Headers" => return Some("section:headers".to_string()),
+                "Request Body" => return Some("section:request-body".to_string()),
                 "Responses" => return Some("section:responses".to_string()),
                 "Examples" => return Some("section:examples".to_string()),
                 "Metadata" => return Some("section:metadata".to_string()),
@@ -205,7 +208,7 @@ pub fn text_document_did_change(content: &str, _uri: Url) -> Vec<Diagnostic> {
             };
 
             // Get the line content to convert byte indices to UTF-16 positions
-            let line = lines.get(diag.line).map(|l| *l).unwrap_or("");
+            let line = lines.get(diag.line).copied().unwrap_or("");
             let line_utf16_len = byte_index_to_utf16_col(line, line.len());
 
             // Convert byte indices to UTF-16 code unit offsets
@@ -220,7 +223,7 @@ pub fn text_document_did_change(content: &str, _uri: Url) -> Vec<Diagnostic> {
 
             // Handle multi-line diagnostics
             let (end_line, end_char) = if let Some(end_line_num) = diag.end_line {
-                let end_line_content = lines.get(end_line_num).map(|l| *l).unwrap_or("");
+                let end_line_content = lines.get(end_line_num).copied().unwrap_or("");
                 let end_line_utf16_len =
                     byte_index_to_utf16_col(end_line_content, end_line_content.len());
                 let end_char_pos = diag
@@ -1476,9 +1479,9 @@ pub fn semantic_tokens_full(content: &str) -> Option<SemanticTokensResult> {
 
         // Check if we're leaving a section (hit another # header or non-doc line)
         let trimmed = line.trim();
-        if !trimmed.starts_with("///") {
-            in_path_params_section = false;
-        } else if trimmed.starts_with("/// #") && !section_regex.is_match(line) {
+        if !trimmed.starts_with("///")
+            || (trimmed.starts_with("/// #") && !section_regex.is_match(line))
+        {
             in_path_params_section = false;
         }
 
@@ -95,14 +95,14 @@ pub fn is_near_rovo_attribute(content: &str, target_line: usize) -> bool {
     let lines: Vec<&str> = content.lines().collect();
 
     // Look ahead up to 20 lines to find a #[rovo] attribute
-    for i in target_line..std::cmp::min(target_line + 20, lines.len()) {
-        if lines[i].trim() == "#[rovo]" || lines[i].contains("#[") && lines[i].contains("rovo") {
+    for line in lines.iter().skip(target_line).take(20) {
+        if line.trim() == "#[rovo]" || line.contains("#[") && line.contains("rovo") {
             return true;
         }
         // Stop if we hit a non-comment, non-attribute line
-        if !lines[i].trim().starts_with("///")
-            && !lines[i].trim().starts_with("#[")
-            && !lines[i].trim().is_empty()
+        if !line.trim().starts_with("///")
+            && !line.trim().starts_with("#[")
+            && !line.trim().is_empty()
         {
             break;
         }
@@ -134,14 +134,10 @@ pub fn parse_annotations(content: &str) -> Vec<Annotation> {
     let lines: Vec<&str> = content.lines().collect();
     let mut annotations = Vec::new();
 
-    // Find all #[rovo] attributes
-    // Note: We only support the exact `#[rovo]` form for parsing, not variants like
-    // `#[rovo(...)]`. This is intentional - the simpler form matches actual usage.
-    // (is_near_rovo_attribute uses a broader check for LSP feature guards, but
-    // parsing only needs to handle the exact attribute form.)
+    // Find all #[rovo] / #[rovo(...)] attributes
     let mut rovo_positions = Vec::new();
     for (idx, line) in lines.iter().enumerate() {
-        if line.trim() == "#[rovo]" {
+        if is_rovo_attribute(line) {
             rovo_positions.push(idx);
         }
     }
@@ -271,6 +267,12 @@ pub fn parse_annotations(content: &str) -> Vec<Annotation> {
     annotations
 }
 
+/// Check whether a line is a `#[rovo]` or `#[rovo(...)]` attribute
+pub fn is_rovo_attribute(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed == "#[rovo]" || (trimmed.starts_with("#[rovo(") && trimmed.ends_with(")]"))
+}
+
 /// Parse a potentially multi-line response from # Responses section
 /// Format: STATUS: TYPE - DESCRIPTION (description can continue on following lines)
 /// Returns the annotation and the number of lines consumed
@@ -339,6 +341,18 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
             break;
         }
 
+        // Indented `header Name: Type - description` lines document response
+        // headers and are not part of the description
+        let after_slashes = next_line.trim_start().trim_start_matches("///");
+        let is_indented = after_slashes
+            .strip_prefix(' ')
+            .unwrap_or(after_slashes)
+            .starts_with(char::is_whitespace);
+        if is_indented && next_content.starts_with("header ") {
+            lines_consumed += 1;
+            continue;
+        }
+
         // This is a continuation line
         description_parts.push(next_content);
         lines_consumed += 1;
@@ -354,6 +368,23 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
     Some((ann, lines_consumed))
 }
 
+/// Split an example line into its status code and the code after the colon
+/// Accepts both `STATUS: CODE` and named `STATUS "name": CODE` entries
+fn split_example_key(content: &str) -> Option<(u16, &str)> {
+    let digits_end = content
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(content.len());
+    let status = content[..digits_end].parse().ok()?;
+
+    let rest = content[digits_end..].trim_start();
+    let rest = match rest.strip_prefix('"') {
+        Some(quoted) => quoted[quoted.find('"')? + 1..].trim_start(),
+        None => rest,
+    };
+
+    Some((status, rest.strip_prefix(':')?.trim()))
+}
+
 /// Count delimiter depths while ignoring delimiters inside string/char literals
 /// Handles regular strings, raw strings (r"...", r#"..."#), and char literals
 /// Returns (brace_depth, bracket_depth, paren_depth)
@@ -460,15 +491,8 @@ fn parse_multiline_example(doc_lines: &[(usize, &str)]) -> Option<(Annotation, u
     let (line_num, first_line) = doc_lines[0];
     let content = first_line.trim_start_matches("///").trim();
 
-    // Check if this line starts with STATUS:
-    let colon_pos = content.find(':')?;
-    let before_colon = content[..colon_pos].trim();
-    if !before_colon.chars().all(|c| c.is_ascii_digit()) {
-        return None;
-    }
-    let status: u16 = before_colon.parse().ok()?;
-
-    let after_colon = content[colon_pos + 1..].trim();
+    // Check if this line starts with STATUS: or STATUS "name":
+    let (status, after_colon) = split_example_key(content)?;
     let mut lines_consumed = 1;
     let mut example_lines = Vec::new();
 
@@ -680,6 +704,68 @@ mod tests {
         assert_eq!(ann.kind, AnnotationKind::Hidden);
     }
 
+    #[test]
+    fn test_parse_response_headers_not_in_description() {
+        let content = r#"
+/// # Responses
+///
+/// 201: Json<User> - User created
+///   header Location: String - URL of the new user
+/// 404: () - User not found
+#[rovo]
+async fn handler() {}
+"#;
+        let annotations = parse_annotations(content);
+        let responses: Vec<_> = annotations
+            .iter()
+            .filter(|a| a.kind == AnnotationKind::Response)
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].description, Some("User created".to_string()));
+        assert_eq!(responses[1].status, Some(404));
+    }
+
+    #[test]
+    fn test_parse_named_examples() {
+        let content = r#"
+/// # Examples
+///
+/// 200 "empty list": vec![]
+/// 200 "one item": vec![Todo::default()]
+#[rovo]
+async fn handler() {}
+"#;
+        let annotations = parse_annotations(content);
+        let examples: Vec<_> = annotations
+            .iter()
+            .filter(|a| a.kind == AnnotationKind::Example)
+            .collect();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].status, Some(200));
+        assert_eq!(examples[0].example_value, Some("vec![]".to_string()));
+        assert_eq!(
+            examples[1].example_value,
+            Some("vec![Todo::default()]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_rovo_attribute_with_arguments() {
+        let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - Successfully retrieved user
+#[rovo(tag = "users", hidden)]
+async fn handler() {}
+"#;
+        let annotations = parse_annotations(content);
+        assert!(annotations
+            .iter()
+            .any(|a| a.kind == AnnotationKind::Response));
+        assert!(is_rovo_attribute("    #[rovo(id = \"getUser\")]"));
+        assert!(!is_rovo_attribute("/// #[rovo(hidden)]"));
+    }
+
     #[test]
     fn test_parse_rust_style_responses() {
         let content = r#"
@@ -1,4 +1,4 @@
-/// Utility functions for LSP position handling
+//! Utility functions for LSP position handling
 
 /// Convert UTF-8 byte index to LSP UTF-16 character position
 ///
@@ -307,7 +307,7 @@ async fn handler() {}
 
     match completions.unwrap() {
         CompletionResponse::Array(items) => {
-            assert!(items.len() > 0);
+            assert!(!items.is_empty());
             assert!(items.iter().any(|i| i.label == "@tag"));
             assert!(items.iter().any(|i| i.label == "@security"));
             assert!(items.iter().any(|i| i.label == "@hidden"));
@@ -112,7 +112,7 @@ fn reports_invalid_example_syntax() {
 async fn handler() {}
 "#;
     let diagnostics = validate_annotations(content);
-    assert!(diagnostics.len() > 0);
+    assert!(!diagnostics.is_empty());
     assert!(
         diagnostics[0].message.contains("Invalid example")
             || diagnostics[0].message.contains("parse")
@@ -0,0 +1,98 @@
+//! Crate-wide settings read from a `rovo.toml` next to the crate's `Cargo.toml`
+
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::parser::types::IdStyle;
+use crate::parser::ParseError;
+
+const FILE_NAME: &str = "rovo.toml";
+
+/// Settings from `rovo.toml`
+#[derive(Debug, Default)]
+pub struct Config {
+    /// Default operation ID style for every handler of the crate
+    pub id_style: Option<IdStyle>,
+}
+
+/// Read the `rovo.toml` of the crate being compiled, returning its path along
+/// with the settings, or `None` if the crate has none
+pub fn load() -> Result<Option<(PathBuf, Config)>, ParseError> {
+    let Some(dir) = std::env::var_os("CARGO_MANIFEST_DIR") else {
+        return Ok(None);
+    };
+    let path = PathBuf::from(dir).join(FILE_NAME);
+
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(ParseError::new(format!(
+                "Failed to read {}: {err}",
+                path.display()
+            )))
+        }
+    };
+
+    let config = parse(&text)
+        .map_err(|message| ParseError::new(format!("Invalid {}: {message}", path.display())))?;
+    Ok(Some((path, config)))
+}
+
+/// Parse the contents of a `rovo.toml`
+fn parse(text: &str) -> Result<Config, String> {
+    let table: toml::Table = text
+        .parse()
+        .map_err(|err: toml::de::Error| err.message().to_string())?;
+
+    let mut config = Config::default();
+    for (key, value) in table {
+        match key.as_str() {
+            "id_style" => {
+                let name = value
+                    .as_str()
+                    .ok_or("'id_style' must be a string, e.g. id_style = \"camel\"")?;
+                let style = IdStyle::from_name(name).ok_or_else(|| {
+                    format!(
+                        "unknown ID style '{name}'\nnote: known styles: {}",
+                        IdStyle::names()
+                    )
+                })?;
+                config.id_style = Some(style);
+            }
+            _ => {
+                return Err(format!(
+                    "unknown setting '{key}'\nnote: supported settings: id_style"
+                ))
+            }
+        }
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_id_style() {
+        let config = parse("id_style = \"module\"").unwrap();
+        assert_eq!(config.id_style, Some(IdStyle::Module));
+        assert_eq!(parse("").unwrap().id_style, None);
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(parse("id_style = \"kebab\"")
+            .unwrap_err()
+            .contains("unknown ID style 'kebab'"));
+        assert!(parse("id_style = 1")
+            .unwrap_err()
+            .contains("must be a string"));
+        assert!(parse("id-style = \"camel\"")
+            .unwrap_err()
+            .contains("unknown setting 'id-style'"));
+        assert!(parse("id_style = ").is_err());
+    }
+}
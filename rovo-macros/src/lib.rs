@@ -16,42 +16,18 @@
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned};
 
+mod config;
 mod parser;
 mod utils;
 
-use parser::{parse_rovo_function, PathParamDoc, PathParamInfo};
-
-/// Known primitive types that map to `OpenAPI` types
-const PRIMITIVE_TYPES: &[&str] = &[
-    "String", "u64", "u32", "u16", "u8", "i64", "i32", "i16", "i8", "bool", "Uuid",
-];
-
-/// Check if a type is a known primitive
-fn is_primitive_type(type_name: &str) -> bool {
-    PRIMITIVE_TYPES.contains(&type_name.trim())
-}
-
-/// Check if a tuple contains only primitives
-fn is_primitive_tuple(type_str: &str) -> bool {
-    let inner = type_str
-        .trim()
-        .trim_start_matches('(')
-        .trim_end_matches(')');
-    inner.split(',').map(str::trim).all(is_primitive_type)
-}
-
-/// Extract individual types from a tuple type string like "(Uuid, u32)"
-fn extract_tuple_types(type_str: &str) -> Vec<String> {
-    let inner = type_str
-        .trim()
-        .trim_start_matches('(')
-        .trim_end_matches(')');
-    inner
-        .split(',')
-        .map(|t| t.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .collect()
-}
+use parser::{
+    is_impl_block, parse_impl_block, parse_rovo_function, DocInfo, FuncItem, HeaderDoc, IdStyle,
+    ImplBlock, ParseError, PathParamDoc, PathParamInfo, QueryParamDoc, QueryParamInfo,
+    RequestBodyDoc, RequestBodyInfo,
+};
+use utils::{
+    extract_tuple_types, is_primitive_tuple, is_primitive_type, to_camel_case, to_identifier,
+};
 
 /// Generate path parameter setters for primitive types
 fn generate_path_param_setters(
@@ -141,6 +117,249 @@ fn generate_path_param_setters(
         .collect()
 }
 
+/// Generate the JSON value expression for a `(default: x)` marker
+///
+/// Values that parse as JSON (numbers, booleans, quoted strings, ...) are used as-is,
+/// anything else is treated as a plain string.
+fn default_value_tokens(default: &str) -> proc_macro2::TokenStream {
+    quote! {
+        ::rovo::__serde_json::from_str(#default)
+            .unwrap_or_else(|_| ::rovo::__serde_json::Value::String(#default.to_string()))
+    }
+}
+
+/// Generate query parameter setters
+///
+/// `Query<T>` struct extractors are already expanded by aide, so their documented
+/// fields are updated in place.
+fn generate_query_param_setters(
+    query_info: Option<&QueryParamInfo>,
+    query_docs: &[QueryParamDoc],
+) -> Vec<proc_macro2::TokenStream> {
+    if query_info.is_none() {
+        return vec![];
+    }
+
+    query_docs
+        .iter()
+        .map(|doc| {
+            let name = &doc.name;
+            let desc = &doc.description;
+
+            let required_setter = if doc.required {
+                quote! { parameter_data.required = true; }
+            } else {
+                quote! {}
+            };
+
+            let default_setter = doc.default.as_deref().map_or_else(
+                || quote! {},
+                |default| {
+                    let value = default_value_tokens(default);
+                    quote! {
+                        if let ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(schema) =
+                            &mut parameter_data.format
+                        {
+                            schema.json_schema.insert("default".to_string(), #value);
+                        }
+                    }
+                },
+            );
+
+            quote! {
+                .with(|mut op| {
+                    for param in &mut op.inner_mut().parameters {
+                        if let ::rovo::aide::openapi::ReferenceOr::Item(
+                            ::rovo::aide::openapi::Parameter::Query { parameter_data, .. }
+                        ) = param {
+                            if parameter_data.name == #name {
+                                parameter_data.description = Some(#desc.to_string());
+                                #required_setter
+                                #default_setter
+                            }
+                        }
+                    }
+                    op
+                })
+            }
+        })
+        .collect()
+}
+
+/// Generate a setter that documents the request body inferred by aide
+///
+/// The example is converted with `serde_json::to_value::<T>` where `T` is the
+/// type of the `Json<T>` / `Form<T>` extractor, so a mismatched example is a
+/// compile error pointing at the doc line.
+fn generate_request_body_setter(
+    body_doc: Option<&RequestBodyDoc>,
+    body_info: Option<&RequestBodyInfo>,
+) -> Option<proc_macro2::TokenStream> {
+    let body_doc = body_doc?;
+
+    let description_setter = body_doc.description.as_ref().map(|desc| {
+        quote! { body.description = Some(#desc.to_string()); }
+    });
+
+    let required_setter = body_doc.required.map(|required| {
+        quote! { body.required = #required; }
+    });
+
+    let content_type_setter = body_doc.content_type.as_ref().map(|content_type| {
+        quote! {
+            let media = ::std::mem::take(&mut body.content)
+                .into_iter()
+                .next()
+                .map(|(_, media)| media)
+                .unwrap_or_default();
+            body.content.insert(#content_type.to_string(), media);
+        }
+    });
+
+    let example_setter = body_doc
+        .example
+        .as_ref()
+        .zip(body_info)
+        .map(|(example, info)| {
+            let body_type = &info.inner_type;
+            let value = quote_spanned! {body_doc.example_span=>
+                ::rovo::__serde_json::to_value::<#body_type>(#example).ok()
+            };
+            quote! {
+                let example = #value;
+                for media in body.content.values_mut() {
+                    media.example = example.clone();
+                }
+            }
+        });
+
+    Some(quote! {
+        .with(|mut op| {
+            if let Some(::rovo::aide::openapi::ReferenceOr::Item(body)) =
+                op.inner_mut().request_body.as_mut()
+            {
+                #description_setter
+                #required_setter
+                #content_type_setter
+                #example_setter
+            }
+            op
+        })
+    })
+}
+
+/// Generate `Response.headers` entries for `header` lines under a response
+fn generate_response_header_setters(header_docs: &[HeaderDoc]) -> Vec<proc_macro2::TokenStream> {
+    header_docs
+        .iter()
+        .map(|doc| {
+            let name = &doc.name;
+            let desc = &doc.description;
+            let required = doc.required;
+            let header_type = &doc.header_type;
+
+            let json_schema = quote_spanned! {doc.span=>
+                <#header_type as ::rovo::schemars::JsonSchema>::json_schema(
+                    &mut ::rovo::schemars::SchemaGenerator::default()
+                )
+            };
+
+            quote! {
+                .with(|mut res| {
+                    res.inner().headers.insert(
+                        #name.to_string(),
+                        ::rovo::aide::openapi::ReferenceOr::Item(::rovo::aide::openapi::Header {
+                            description: Some(#desc.to_string()),
+                            style: ::rovo::aide::openapi::HeaderStyle::Simple,
+                            required: #required,
+                            deprecated: None,
+                            format: ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(
+                                ::rovo::aide::openapi::SchemaObject {
+                                    json_schema: #json_schema,
+                                    example: None,
+                                    external_docs: None,
+                                }
+                            ),
+                            example: None,
+                            examples: ::std::default::Default::default(),
+                            extensions: ::std::default::Default::default(),
+                        }),
+                    );
+                    res
+                })
+            }
+        })
+        .collect()
+}
+
+/// Generate request header parameter setters
+///
+/// Headers already inferred by aide (e.g. from a `TypedHeader<T>` extractor with the
+/// `headers` feature) are matched case-insensitively and updated in place, other
+/// documented headers are added as new parameters.
+fn generate_header_setters(header_docs: &[HeaderDoc]) -> Vec<proc_macro2::TokenStream> {
+    header_docs
+        .iter()
+        .map(|doc| {
+            let name = &doc.name;
+            let desc = &doc.description;
+            let required = doc.required;
+            let header_type = &doc.header_type;
+
+            let json_schema = quote_spanned! {doc.span=>
+                <#header_type as ::rovo::schemars::JsonSchema>::json_schema(
+                    &mut ::rovo::schemars::SchemaGenerator::default()
+                )
+            };
+
+            quote! {
+                .with(|mut op| {
+                    let format = ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(
+                        ::rovo::aide::openapi::SchemaObject {
+                            json_schema: #json_schema,
+                            example: None,
+                            external_docs: None,
+                        }
+                    );
+
+                    let existing = op.inner_mut().parameters.iter_mut().find_map(|param| match param {
+                        ::rovo::aide::openapi::ReferenceOr::Item(
+                            ::rovo::aide::openapi::Parameter::Header { parameter_data, .. }
+                        ) if parameter_data.name.eq_ignore_ascii_case(#name) => Some(parameter_data),
+                        _ => None,
+                    });
+
+                    if let Some(parameter_data) = existing {
+                        parameter_data.description = Some(#desc.to_string());
+                        parameter_data.required |= #required;
+                        parameter_data.format = format;
+                    } else {
+                        op.inner_mut().parameters.push(
+                            ::rovo::aide::openapi::ReferenceOr::Item(
+                                ::rovo::aide::openapi::Parameter::Header {
+                                    parameter_data: ::rovo::aide::openapi::ParameterData {
+                                        name: #name.to_string(),
+                                        description: Some(#desc.to_string()),
+                                        required: #required,
+                                        deprecated: None,
+                                        format,
+                                        example: None,
+                                        examples: ::std::default::Default::default(),
+                                        explode: None,
+                                        extensions: ::std::default::Default::default(),
+                                    },
+                                    style: ::rovo::aide::openapi::HeaderStyle::Simple,
+                                }
+                            )
+                        );
+                    }
+                    op
+                })
+            }
+        })
+        .collect()
+}
+
 /// Macro that generates `OpenAPI` documentation from doc comments.
 ///
 /// This macro automatically generates `OpenAPI` documentation for your handlers
@@ -152,7 +371,11 @@ fn generate_path_param_setters(
 ///
 /// ## Sections
 /// - `# Path Parameters` - Document path parameters for primitive types
-/// - `# Responses` - Document response status codes
+/// - `# Query Parameters` - Document query parameters, with required/default markers
+/// - `# Headers` - Document request headers
+/// - `# Request Body` - Describe the request body and give an example
+/// - `# Responses` - Document response status codes (inferred from a concrete return type when absent)
+///   Handlers returning `Result<T, E>` also inherit the responses `E` declares via `rovo::ErrorResponses`
 /// - `# Examples` - Provide example responses
 /// - `# Metadata` - Add tags, security, and other metadata
 ///
@@ -173,13 +396,124 @@ fn generate_path_param_setters(
 ///
 /// For complex types, continue using structs with `#[derive(JsonSchema)]`.
 ///
+/// ## Query Parameters
+///
+/// Query parameters are documented the same way, with optional `(required)` and
+/// `(default: <value>)` markers:
+///
+/// ```rust,ignore
+/// /// # Query Parameters
+/// ///
+/// /// page: Page number to return (default: 1)
+/// /// q: Search term (required)
+/// ```
+///
+/// Names are matched against the destructured fields of
+/// `Query(Params { page, q }): Query<Params>`. For an opaque binding like
+/// `Query(params): Query<Params>`, the entries update the parameters generated
+/// from the struct's `JsonSchema`. `Query<T>` must be a struct deriving
+/// `Deserialize` and `JsonSchema`; a primitive like `Query<String>` fails to compile.
+///
+/// ## Headers
+///
+/// Request headers are documented with their type and an optional `(required)` marker:
+///
+/// ```rust,ignore
+/// /// # Headers
+/// ///
+/// /// X-Tenant-Id: Uuid (required) - Tenant the request acts on
+/// /// If-Match: String - Only update if the entity tag matches
+/// ```
+///
+/// With the `headers` feature, `TypedHeader<T>` extractors are documented
+/// automatically; a `# Headers` entry with the same (case-insensitive) name adds
+/// its description to the inferred parameter instead of duplicating it.
+///
+/// ## Response Headers
+///
+/// Headers sent with a response are listed on indented `header` lines directly
+/// below the response they belong to, using the same format as `# Headers`:
+///
+/// ```rust,ignore
+/// /// # Responses
+/// ///
+/// /// 201: Json<Item> - Item created
+/// ///   header Location: String (required) - URL of the created resource
+/// /// 429: () - Too many requests
+/// ///   header Retry-After: u32 - Seconds to wait before retrying
+/// ```
+///
+/// ## Request Body
+///
+/// Free-form lines describe the body inferred from a `Json<T>` or `Form<T>`
+/// extractor. `(optional)` / `(required)` markers override whether it is
+/// required, `Content-Type:` replaces the media type, and `Example:` takes a
+/// Rust expression that must have type `T`:
+///
+/// ```rust,ignore
+/// /// # Request Body
+/// ///
+/// /// The todo item to create
+/// /// Content-Type: application/json
+/// /// Example: CreateTodo { title: "Buy milk".into() }
+/// ```
+///
+/// ## Named Examples
+///
+/// Several examples for one status code need a name, which becomes the key and
+/// summary in the `OpenAPI` `examples` map:
+///
+/// ```rust,ignore
+/// /// # Examples
+/// ///
+/// /// 200 "empty list": vec![]
+/// /// 200 "one item": vec![Todo::default()]
+/// ```
+///
 /// ## Metadata Annotations
 /// - `@tag <tag_name>` - Add a tag for grouping operations (can be used multiple times)
-/// - `@security <scheme_name>` - Add security requirements (can be used multiple times)
+/// - `@security <scheme_name>` - Add security requirements (can be used multiple times); the
+///   scheme must be defined with `Router::security_scheme`
 /// - `@id <operation_id>` - Set a custom operation ID (defaults to function name)
 /// - `@hidden` - Hide this operation from documentation
 /// - `@rovo-ignore` - Stop processing annotations after this point
 ///
+/// ## Attribute Arguments
+///
+/// Metadata can also be passed as `#[rovo(...)]` arguments, which is handy for
+/// code generated by other macros. They are merged with the `# Metadata`
+/// section; an `id` given in both places must match.
+///
+/// ```rust,ignore
+/// #[rovo(tag = "users", security = "bearer_auth", id = "getUserById", hidden)]
+/// ```
+///
+/// `id_style` picks how the operation ID is derived when there is no `@id`:
+/// `"name"` (the default, `get_user`), `"module"` (`api_users_get_user`),
+/// `"camel"` (`getUser`) or `"tag"` (`users_get_user`). A crate-wide style can
+/// be set with `id_style = "..."` in a `rovo.toml` next to the crate's `Cargo.toml`.
+///
+/// ## Warnings
+///
+/// Handlers without responses, unknown `# Section` headers, primitive path
+/// bindings missing from `# Path Parameters` and `HeaderMap` arguments without
+/// `# Headers` raise compiler warnings. Silence them with
+/// `#[rovo(allow(missing_responses, unknown_sections, undocumented_path_params, undocumented_headers))]`.
+///
+/// ## Impl Blocks
+///
+/// When placed on an inherent `impl` block, every method inside it that is
+/// itself marked `#[rovo]` is turned into an associated `rovo::DocumentedHandler`
+/// const of the same name, so `Users::list` can be passed to `routing::get`.
+/// Such methods cannot take `self`.
+///
+/// ## Generic Handlers
+///
+/// Generic handlers, including ones with a `where` clause, expand to a
+/// function returning a `rovo::DocumentedHandler` that carries the generics,
+/// so they are routed as `get(list::<PgRepo>)`. Lifetime parameters are not
+/// supported.
+///
 /// Additionally, the Rust `#[deprecated]` attribute is automatically detected
 /// and will mark the operation as deprecated in the `OpenAPI` spec.
 ///
@@ -269,194 +603,519 @@ fn generate_path_param_setters(
 /// }
 /// ```
 #[proc_macro_attribute]
-pub fn rovo(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = item;
+pub fn rovo(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input: proc_macro2::TokenStream = item.into();
 
-    match parse_rovo_function(input.into()) {
-        Ok((func_item, doc_info)) => {
-            let func_name = &func_item.name;
+    let result = if is_impl_block(&input) {
+        expand_impl_block(attr.into(), input)
+    } else {
+        expand_handler(attr.into(), input)
+    };
 
-            let title = doc_info.title.as_deref().unwrap_or("");
-            let description = doc_info.description.as_deref().unwrap_or("");
+    result.unwrap_or_else(|err| error_tokens(&err)).into()
+}
 
-            // Generate response setters if we have doc comments
-            let response_code_setters = if doc_info.responses.is_empty() {
-                // No responses specified - generate a minimal docs function
-                vec![]
-            } else {
-                doc_info
-                    .responses
-                    .iter()
-                    .map(|resp| {
-                        let code = resp.status_code;
-                        let response_type = &resp.response_type;
-                        let desc = &resp.description;
-
-                        // Check if there's an explicit example for this status code
-                        doc_info
-                            .examples
-                            .iter()
-                            .find(|e| e.status_code == code)
-                            .map_or_else(
-                                || {
-                                    // No explicit example, just add the description
-                                    quote! {
-                                        .response_with::<#code, #response_type, _>(|res| {
-                                            res.description(#desc)
-                                        })
-                                    }
-                                },
-                                |example| {
-                                    let example_code = &example.example_code;
-                                    quote! {
-                                        .response_with::<#code, #response_type, _>(|res| {
-                                            res.description(#desc)
-                                                .example(#example_code)
-                                        })
-                                    }
-                                },
-                            )
-                    })
-                    .collect()
-            };
+/// Build one `compile_error!` invocation per problem in a parse error
+fn error_tokens(err: &ParseError) -> proc_macro2::TokenStream {
+    err.iter()
+        .map(|err| {
+            let err_msg = err.to_string();
+            // Use the span from the error if available, otherwise use call_site
+            err.span().map_or_else(
+                || {
+                    quote! {
+                        compile_error!(#err_msg);
+                    }
+                },
+                |span| {
+                    quote_spanned! {span=>
+                        compile_error!(#err_msg);
+                    }
+                },
+            )
+        })
+        .collect()
+}
 
-            // Generate tag setters
-            let tag_setters: Vec<_> = doc_info
-                .tags
-                .iter()
-                .map(|tag| {
-                    quote! { .tag(#tag) }
-                })
-                .collect();
+/// Description for an inferred response whose body type has no doc comment
+const fn default_response_description(status_code: u16) -> &'static str {
+    match status_code {
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No content",
+        _ => "Successful response",
+    }
+}
 
-            // Generate security requirement setters
-            let security_setters: Vec<_> = doc_info
-                .security_requirements
-                .iter()
-                .map(|scheme| {
-                    quote! { .security_requirement(#scheme) }
-                })
-                .collect();
+/// Generate the body of a handler's docs function, applying everything
+/// documented on it to the `op` transform
+fn generate_operation_docs(func_item: &FuncItem, doc_info: &DocInfo) -> proc_macro2::TokenStream {
+    let func_name = &func_item.name;
 
-            // Generate operation ID setter
-            let operation_id_setter = doc_info.operation_id.as_ref().map_or_else(
-                || {
-                    // Default to function name if no custom ID provided
-                    let default_id = func_name.to_string();
-                    quote! { .id(#default_id) }
-                },
-                |id| quote! { .id(#id) },
-            );
+    let title = doc_info.title.as_deref().unwrap_or("");
+    let description = doc_info.description.as_deref().unwrap_or("");
 
-            // Generate deprecated setter
-            let deprecated_setter = if doc_info.deprecated {
-                quote! { .with(|mut op| { op.inner_mut().deprecated = true; op }) }
-            } else {
-                quote! {}
-            };
+    // Explicit examples for a status code: a single unnamed one sets
+    // `example`, named ones fill the `examples` map
+    let example_setters = |code: u16| {
+        doc_info
+            .examples
+            .iter()
+            .filter(move |e| e.status_code == code)
+            .map(|example| {
+                let example_code = &example.example_code;
+                example.name.as_ref().map_or_else(
+                    || quote! { .example(#example_code) },
+                    |name| {
+                        quote! {
+                            .with(|res| ::rovo::__named_example(res, #name, #example_code))
+                        }
+                    },
+                )
+            })
+    };
 
-            // Generate hidden setter
-            let hidden_setter = if doc_info.hidden {
-                quote! { .hidden(true) }
-            } else {
-                quote! {}
-            };
+    // Generate response setters if we have doc comments
+    let response_code_setters: Vec<_> = if doc_info.responses.is_empty() {
+        // No responses specified - fall back to the one inferred from the return type
+        func_item
+            .inferred_response
+            .iter()
+            .map(|inferred| {
+                let code = inferred.status_code;
+                let response_type = &inferred.response_type;
+                let fallback = default_response_description(code);
+                let desc = inferred.body_type.as_ref().map_or_else(
+                    || quote! { #fallback },
+                    |body_type| quote! { &::rovo::__type_description::<#body_type>(#fallback) },
+                );
+                let example_setters = example_setters(code);
 
-            // Generate path parameter setters for primitive types
-            let path_param_setters =
-                generate_path_param_setters(func_item.path_params.as_ref(), &doc_info.path_params);
-
-            // Generate an internal implementation name
-            let impl_name = quote::format_ident!("__{}_impl", func_name);
-
-            // Get the renamed function tokens
-            let impl_func = func_item.with_renamed(&impl_name);
-
-            // Create a const with an uppercase version of the handler name
-            let const_name = quote::format_ident!("{}", func_name.to_string().to_uppercase());
-
-            // Determine the state type for the trait implementation
-            let state_type = func_item
-                .state_type
-                .as_ref()
-                .map_or_else(|| quote! { () }, |st| quote! { #st });
-
-            let output = quote! {
-                // Internal implementation with renamed function
-                #[allow(non_snake_case, private_interfaces)]
-                #impl_func
-
-                // Create a zero-sized type that can be passed to routing functions
-                #[allow(non_camel_case_types)]
-                #[derive(Clone, Copy)]
-                pub struct #func_name;
-
-                impl #func_name {
-                    #[doc(hidden)]
-                    pub fn __docs(op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation {
-                        op
-                            #operation_id_setter
-                            .summary(#title)
-                            .description(#description)
-                            #(#tag_setters)*
-                            #deprecated_setter
-                            #hidden_setter
-                            #(#security_setters)*
-                            #(#path_param_setters)*
-                            #(#response_code_setters)*
-                    }
+                quote! {
+                    .response_with::<#code, #response_type, _>(|res| {
+                        res.description(#desc)
+                            #(#example_setters)*
+                    })
                 }
+            })
+            .collect()
+    } else {
+        doc_info
+            .responses
+            .iter()
+            .map(|resp| {
+                let code = resp.status_code;
+                let response_type = &resp.response_type;
+                let desc = &resp.description;
+                let header_setters = generate_response_header_setters(&resp.headers);
+                let example_setters = example_setters(code);
 
-                // Implement the IntoApiMethodRouter trait
-                impl ::rovo::IntoApiMethodRouter<#state_type> for #func_name {
-                    fn into_get_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::get_with(#impl_name, Self::__docs)
-                    }
+                quote! {
+                    .response_with::<#code, #response_type, _>(|res| {
+                        res.description(#desc)
+                            #(#header_setters)*
+                            #(#example_setters)*
+                    })
+                }
+            })
+            .collect()
+    };
 
-                    fn into_post_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::post_with(#impl_name, Self::__docs)
-                    }
+    // Generate tag setters
+    let tag_setters: Vec<_> = doc_info
+        .tags
+        .iter()
+        .map(|tag| {
+            quote! { .tag(#tag) }
+        })
+        .collect();
 
-                    fn into_patch_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::patch_with(#impl_name, Self::__docs)
-                    }
+    // Generate security requirement setters
+    let security_setters: Vec<_> = doc_info
+        .security_requirements
+        .iter()
+        .map(|scheme| {
+            quote! { .security_requirement(#scheme) }
+        })
+        .collect();
 
-                    fn into_delete_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::delete_with(#impl_name, Self::__docs)
-                    }
+    // Generate operation ID setter
+    let operation_id_setter = doc_info.operation_id.as_ref().map_or_else(
+        || default_operation_id(func_name, doc_info),
+        |id| quote! { .id(#id) },
+    );
 
-                    fn into_put_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::put_with(#impl_name, Self::__docs)
-                    }
-                }
+    // Generate deprecated setter
+    let deprecated_setter = if doc_info.deprecated {
+        quote! { .with(|mut op| { op.inner_mut().deprecated = true; op }) }
+    } else {
+        quote! {}
+    };
 
-                // Also create a CONST for explicit use
-                #[allow(non_upper_case_globals)]
-                pub const #const_name: #func_name = #func_name;
-            };
+    // Record the handler's module, used to disambiguate duplicate operation IDs
+    // at finish time, where the extension is removed again
+    let module_path_setter = quote! {
+        .with(|mut op| {
+            op.inner_mut().extensions.insert(
+                ::rovo::__MODULE_PATH_EXTENSION.to_string(),
+                ::rovo::__serde_json::Value::from(::core::module_path!()),
+            );
+            op
+        })
+    };
 
-            output.into()
+    // Generate hidden setter
+    let hidden_setter = if doc_info.hidden {
+        quote! { .hidden(true) }
+    } else {
+        quote! {}
+    };
+
+    // Generate path parameter setters for primitive types
+    let path_param_setters =
+        generate_path_param_setters(func_item.path_params.as_ref(), &doc_info.path_params);
+
+    // Generate query parameter setters
+    let query_param_setters =
+        generate_query_param_setters(func_item.query_params.as_ref(), &doc_info.query_params);
+
+    // Generate request header setters
+    let header_setters = generate_header_setters(&doc_info.headers);
+
+    // Generate request body setter
+    let request_body_setter = generate_request_body_setter(
+        doc_info.request_body.as_ref(),
+        func_item.request_body.as_ref(),
+    );
+
+    // Merge the responses declared by the error type of a `Result<T, E>`;
+    // they come last so explicit and inferred responses take precedence
+    let error_response_setter = func_item.error_type.as_ref().map(|error_type| {
+        quote! {
+            .with(|op| {
+                #[allow(unused_imports)]
+                use ::rovo::{__ErrorResponsesFallback as _, __ErrorResponsesViaTrait as _};
+                (&::rovo::__ErrorResponsesProbe::<#error_type>::new()).__error_responses(op)
+            })
         }
-        Err(err) => {
-            let err_msg = err.to_string();
-            // Use the span from the error if available, otherwise use call_site
-            let error_tokens = err.span().map_or_else(
-                || {
-                    quote! {
-                        compile_error!(#err_msg);
-                    }
-                },
-                |span| {
-                    quote_spanned! {span=>
-                        compile_error!(#err_msg);
-                    }
-                },
+    });
+
+    quote! {
+        op
+            #operation_id_setter
+            #module_path_setter
+            .summary(#title)
+            .description(#description)
+            #(#tag_setters)*
+            #deprecated_setter
+            #hidden_setter
+            #(#security_setters)*
+            #(#path_param_setters)*
+            #(#query_param_setters)*
+            #(#header_setters)*
+            #request_body_setter
+            #(#response_code_setters)*
+            #error_response_setter
+    }
+}
+
+/// Operation ID setter for a handler without `@id`, following its ID style
+fn default_operation_id(func_name: &syn::Ident, doc_info: &DocInfo) -> proc_macro2::TokenStream {
+    let name = func_name.to_string();
+    let id = match doc_info.id_style {
+        IdStyle::Name => quote! { #name },
+        IdStyle::Camel => {
+            let id = to_camel_case(&name);
+            quote! { #id }
+        }
+        IdStyle::Tag => {
+            let id = doc_info.tags.first().map_or_else(
+                || name.clone(),
+                |tag| format!("{}_{name}", to_identifier(tag)),
             );
-            error_tokens.into()
+            quote! { #id }
         }
+        // The module path is only known once the macro's output is compiled
+        IdStyle::Module => quote! {
+            ::rovo::__module_operation_id(::core::module_path!(), #name)
+        },
+    };
+
+    // Cargo doesn't watch `rovo.toml`, so include it to rebuild when it changes
+    match &doc_info.config_file {
+        Some(path) => quote! {
+            .id(&{
+                const _: &[u8] = ::core::include_bytes!(#path);
+                #id
+            })
+        },
+        None if doc_info.id_style == IdStyle::Module => quote! { .id(&#id) },
+        None => quote! { .id(#id) },
     }
 }
 
+/// Generate compiler warnings for soft documentation problems
+///
+/// Stable Rust has no way for a proc macro to emit warnings, so each one uses
+/// a deprecated constant, spanned at the offending line.
+fn generate_warnings(doc_info: &DocInfo) -> proc_macro2::TokenStream {
+    doc_info
+        .warnings
+        .iter()
+        .map(|warning| {
+            let message = &warning.message;
+            let name = quote::format_ident!("{}", warning.kind.name(), span = warning.span);
+            quote_spanned! {warning.span=>
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const #name: () = ();
+                    #name
+                };
+            }
+        })
+        .collect()
+}
+
+/// Determine the state type for a handler's router
+fn state_type(func_item: &FuncItem) -> proc_macro2::TokenStream {
+    func_item
+        .state_type
+        .as_ref()
+        .map_or_else(|| quote! { () }, |st| quote! { #st })
+}
+
+/// Generate one match arm per HTTP method for a `DocumentedHandler`, all
+/// sharing the same handler and docs functions
+fn generate_route_arms(
+    handler: &proc_macro2::TokenStream,
+    docs: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    [
+        ("Get", "get_with"),
+        ("Post", "post_with"),
+        ("Patch", "patch_with"),
+        ("Delete", "delete_with"),
+        ("Put", "put_with"),
+        ("Head", "head_with"),
+        ("Options", "options_with"),
+        ("Trace", "trace_with"),
+    ]
+    .into_iter()
+    .map(|(variant, route_fn)| {
+        let variant = quote::format_ident!("{}", variant);
+        let route_fn = quote::format_ident!("{}", route_fn);
+        quote! {
+            ::rovo::__RouteMethod::#variant => {
+                ::rovo::aide::axum::routing::#route_fn(#handler, #docs)
+            }
+        }
+    })
+    .collect()
+}
+
+/// Expand `#[rovo]` on a free-standing handler function
+fn expand_handler(
+    attr: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, ParseError> {
+    let (func_item, doc_info) = parse_rovo_function(attr, input)?;
+    let func_name = &func_item.name;
+    let operation_docs = generate_operation_docs(&func_item, &doc_info);
+    let warnings = generate_warnings(&doc_info);
+
+    // Generate an internal implementation name
+    let impl_name = quote::format_ident!("__{}_impl", func_name);
+
+    // Get the renamed function tokens
+    let impl_func = func_item.with_renamed(&impl_name);
+
+    // Create a const with an uppercase version of the handler name
+    let const_name = quote::format_ident!("{}", func_name.to_string().to_uppercase());
+
+    // Determine the state type for the trait implementation
+    let state_type = state_type(&func_item);
+
+    // A unit struct cannot carry generics, so generic handlers get a function
+    // returning a `DocumentedHandler` instead (`list::<PgRepo>`)
+    if !func_item.generics.params.is_empty() {
+        let docs_name = quote::format_ident!("__{}_docs", func_name);
+        let (impl_generics, ty_generics, where_clause) = func_item.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+        let routes = generate_route_arms(
+            &quote! { #impl_name #turbofish },
+            &quote! { #docs_name #turbofish },
+        );
+
+        return Ok(quote! {
+            // Internal implementation with renamed function
+            #[allow(non_snake_case, private_bounds, private_interfaces)]
+            #impl_func
+
+            #[doc(hidden)]
+            #[allow(private_bounds)]
+            pub fn #docs_name #impl_generics (op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation #where_clause {
+                #operation_docs
+            }
+
+            #[allow(private_bounds, private_interfaces)]
+            pub fn #func_name #impl_generics () -> ::rovo::DocumentedHandler<#state_type> #where_clause {
+                ::rovo::DocumentedHandler::__new(|method| match method {
+                    #(#routes)*
+                })
+            }
+
+            #warnings
+        });
+    }
+
+    let output = quote! {
+        // Internal implementation with renamed function
+        #[allow(non_snake_case, private_bounds, private_interfaces)]
+        #impl_func
+
+        // Create a zero-sized type that can be passed to routing functions
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy)]
+        pub struct #func_name;
+
+        impl #func_name {
+            #[doc(hidden)]
+            pub fn __docs(op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation {
+                #operation_docs
+            }
+        }
+
+        // Implement the IntoApiMethodRouter trait
+        impl ::rovo::IntoApiMethodRouter<#state_type> for #func_name {
+            fn into_get_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::get_with(#impl_name, Self::__docs)
+            }
+
+            fn into_post_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::post_with(#impl_name, Self::__docs)
+            }
+
+            fn into_patch_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::patch_with(#impl_name, Self::__docs)
+            }
+
+            fn into_delete_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::delete_with(#impl_name, Self::__docs)
+            }
+
+            fn into_put_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::put_with(#impl_name, Self::__docs)
+            }
+
+            fn into_head_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::head_with(#impl_name, Self::__docs)
+            }
+
+            fn into_options_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::options_with(#impl_name, Self::__docs)
+            }
+
+            fn into_trace_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::trace_with(#impl_name, Self::__docs)
+            }
+        }
+
+        // Also create a CONST for explicit use
+        #[allow(non_upper_case_globals)]
+        pub const #const_name: #func_name = #func_name;
+
+        #warnings
+    };
+
+    Ok(output)
+}
+
+/// Expand `#[rovo]` on an `impl` block, replacing every `#[rovo]` method with
+/// an associated `rovo::DocumentedHandler` const of the same name
+fn expand_impl_block(
+    attr: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, ParseError> {
+    let ImplBlock { mut item, methods } = parse_impl_block(attr, input)?;
+    let mut errors = Vec::new();
+    // Warnings cannot live inside the impl block, they are emitted after it
+    let mut warnings = proc_macro2::TokenStream::new();
+
+    for method in methods {
+        let (func_item, doc_info) = match parse_rovo_function(method.attr, method.tokens) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                // Keep going so problems in every method are reported
+                errors.push(err);
+                continue;
+            }
+        };
+        let func_name = &func_item.name;
+        let operation_docs = generate_operation_docs(&func_item, &doc_info);
+        warnings.extend(generate_warnings(&doc_info));
+
+        let impl_name = quote::format_ident!("__{}_impl", func_name);
+        let docs_name = quote::format_ident!("__{}_docs", func_name);
+        let impl_func = func_item.with_renamed(&impl_name);
+        let state_type = state_type(&func_item);
+        let vis = &method.vis;
+        let doc_attrs = &method.doc_attrs;
+
+        let (impl_generics, ty_generics, where_clause) = func_item.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+        let routes = generate_route_arms(
+            &quote! { Self::#impl_name #turbofish },
+            &quote! { Self::#docs_name #turbofish },
+        );
+
+        // Generic methods need a function to carry their generics, others
+        // become a const so they can be passed without calling
+        let handle = if func_item.generics.params.is_empty() {
+            quote! {
+                #[allow(non_upper_case_globals)]
+                #vis const #func_name: ::rovo::DocumentedHandler<#state_type> =
+                    ::rovo::DocumentedHandler::__new(|method| match method {
+                        #(#routes)*
+                    });
+            }
+        } else {
+            quote! {
+                #[allow(private_bounds, private_interfaces)]
+                #vis fn #func_name #impl_generics () -> ::rovo::DocumentedHandler<#state_type> #where_clause {
+                    ::rovo::DocumentedHandler::__new(|method| match method {
+                        #(#routes)*
+                    })
+                }
+            }
+        };
+
+        item.items.push(syn::ImplItem::Verbatim(quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case, private_bounds, private_interfaces)]
+            #impl_func
+
+            #[doc(hidden)]
+            #[allow(private_bounds)]
+            pub fn #docs_name #impl_generics (op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation #where_clause {
+                #operation_docs
+            }
+
+            #(#doc_attrs)*
+            #handle
+        }));
+    }
+
+    if let Some(err) = ParseError::combine(errors) {
+        return Err(err);
+    }
+
+    Ok(quote! {
+        #item
+        #warnings
+    })
+}
+
 /// Derive macro for [`JsonSchema`](trait@::schemars::JsonSchema) that automatically
 /// resolves rovo's re-exported `schemars` crate path.
 ///
@@ -486,6 +1145,27 @@ pub fn derive_json_schema(input: TokenStream) -> TokenStream {
     let hidden_name = quote::format_ident!("__rovo_{}", original_name);
     hidden.ident = hidden_name.clone();
 
+    // Doc comments are stripped from the hidden type below, so hand them to
+    // schemars as its title and description instead.
+    let doc_lines: Vec<&syn::Expr> = hidden
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| attr.meta.require_name_value().ok())
+        .map(|name_value| &name_value.value)
+        .collect();
+    let doc_attrs = if doc_lines.is_empty() {
+        quote! {}
+    } else {
+        let doc = quote! { ::core::concat!(#(#doc_lines, "\n"),*) };
+        let doc = quote! { ::rovo::__title_and_description(#doc) };
+        let title = (!has_schemars_arg(&hidden.attrs, "title"))
+            .then(|| quote! { #[schemars(title = #doc.0)] });
+        let description = (!has_schemars_arg(&hidden.attrs, "description"))
+            .then(|| quote! { #[schemars(description = #doc.1)] });
+        quote! { #title #description }
+    };
+
     // Strip #[derive(...)] and #[doc(...)] attributes — keep schemars/serde/validate/garde.
     hidden.attrs.retain(|attr| {
         let path = attr.path();
@@ -493,20 +1173,7 @@ pub fn derive_json_schema(input: TokenStream) -> TokenStream {
     });
 
     // Only inject the crate path if the user hasn't set one already.
-    let has_crate_attr = hidden.attrs.iter().any(|attr| {
-        if !attr.path().is_ident("schemars") {
-            return false;
-        }
-        let syn::Meta::List(list) = &attr.meta else {
-            return false;
-        };
-        list.tokens
-            .clone()
-            .into_iter()
-            .any(|tt| matches!(tt, proc_macro2::TokenTree::Ident(ref ident) if ident == "crate"))
-    });
-
-    let crate_attr = if has_crate_attr {
+    let crate_attr = if has_schemars_arg(&hidden.attrs, "crate") {
         quote! {}
     } else {
         quote! { #[schemars(crate = "::rovo::schemars")] }
@@ -525,6 +1192,7 @@ pub fn derive_json_schema(input: TokenStream) -> TokenStream {
         const _: () = {
             #[derive(::rovo::__schemars::JsonSchema)]
             #crate_attr
+            #doc_attrs
             #[allow(dead_code, non_camel_case_types)]
             #hidden
 
@@ -560,3 +1228,19 @@ pub fn derive_json_schema(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Check whether a `#[schemars(...)]` attribute sets the given argument
+fn has_schemars_arg(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("schemars") {
+            return false;
+        }
+        let syn::Meta::List(list) = &attr.meta else {
+            return false;
+        };
+        list.tokens
+            .clone()
+            .into_iter()
+            .any(|tt| matches!(tt, proc_macro2::TokenTree::Ident(ref ident) if ident == name))
+    })
+}
@@ -1,6 +1,6 @@
 use super::error::ParseError;
-use super::types::{ExampleInfo, ResponseInfo};
-use proc_macro2::{Span, TokenStream};
+use super::types::{ExampleInfo, HeaderDoc, QueryParamDoc, ResponseInfo};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 
 /// Macro to parse simple annotations with format: @name <value>
 macro_rules! parse_simple_annotation {
@@ -83,6 +83,7 @@ pub fn parse_response_from_parts(
         status_code,
         response_type,
         description: description.to_string(),
+        headers: Vec::new(),
     })
 }
 
@@ -103,6 +104,72 @@ pub fn parse_example_from_parts(
         ));
     }
 
+    let example_code = parse_example_expr(example_code_str, span)?;
+
+    Ok(ExampleInfo {
+        status_code,
+        name: None,
+        example_code,
+        span,
+    })
+}
+
+/// Status code, optional name and code of an `# Examples` line
+type ExampleKey<'a> = (&'a str, Option<String>, &'a str);
+
+/// Split an `# Examples` line into its status code, optional name and code
+///
+/// Accepts `<status>: <expr>` and `<status> "name": <expr>`. Returns `None` for
+/// lines that don't start a new example. Quotes arrive escaped (`\"`) from
+/// the doc attribute.
+pub fn split_example_key(trimmed: &str, span: Span) -> Result<Option<ExampleKey<'_>>, ParseError> {
+    let digits_end = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (status, rest) = trimmed.split_at(digits_end);
+
+    if let Some(code) = rest.strip_prefix(':') {
+        return Ok(Some((status, None, code)));
+    }
+
+    let Some(quoted) = rest.trim_start().strip_prefix("\\\"") else {
+        return Ok(None);
+    };
+    if status.is_empty() {
+        return Ok(None);
+    }
+
+    let format_error = |message: &str| {
+        ParseError::with_span(
+            format!(
+                "{message}\n\
+                 help: named examples use '<status> \"<name>\": <expression>'\n\
+                 note: example '200 \"empty list\": vec![]'"
+            ),
+            span,
+        )
+    };
+
+    let end = quoted
+        .find("\\\"")
+        .ok_or_else(|| format_error("Unclosed example name"))?;
+    let name = quoted[..end].trim();
+    if name.is_empty() {
+        return Err(format_error("Empty example name"));
+    }
+
+    let code = quoted[end + 2..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(|| format_error("Missing ':' after example name"))?;
+
+    Ok(Some((status, Some(name.to_string()), code)))
+}
+
+/// Parse an example expression written in a doc comment into tokens
+///
+/// Shared by `# Examples` entries and the `# Request Body` example.
+pub fn parse_example_expr(example_code_str: &str, span: Span) -> Result<TokenStream, ParseError> {
     // Unescape quotes that come from doc comments
     let unescaped = example_code_str.replace("\\\"", "\"");
 
@@ -131,9 +198,202 @@ pub fn parse_example_from_parts(
         )
     })?;
 
-    Ok(ExampleInfo {
-        status_code,
-        example_code,
+    Ok(example_code)
+}
+
+/// Parse the `Example:` expression of the `# Request Body` section
+pub fn parse_request_body_example(
+    example_code_str: &str,
+    span: Span,
+) -> Result<TokenStream, ParseError> {
+    if example_code_str.trim().is_empty() {
+        return Err(ParseError::with_span(
+            "Empty request body example\n\
+             help: provide a valid Rust expression\n\
+             note: format is 'Example: <rust_expression>'",
+            span,
+        ));
+    }
+
+    // Point type mismatches against the body type at the doc line
+    parse_example_expr(example_code_str, span).map(|example| respan(example, span))
+}
+
+/// Parse the value of a `Content-Type:` line in the `# Request Body` section
+pub fn parse_content_type(value: &str, span: Span) -> Result<String, ParseError> {
+    let content_type = value.trim();
+    let is_valid = content_type.split_once('/').is_some_and(|(kind, subtype)| {
+        !kind.is_empty()
+            && !subtype.is_empty()
+            && !content_type.contains(char::is_whitespace)
+            && !subtype.contains('/')
+    });
+
+    if !is_valid {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid content type '{content_type}'\n\
+                 help: use a media type like 'application/json'\n\
+                 note: format is 'Content-Type: <type>/<subtype>'"
+            ),
+            span,
+        ));
+    }
+
+    Ok(content_type.to_string())
+}
+
+/// Parse a query parameter line (for the `# Query Parameters` section)
+///
+/// Format: `<name>: <description>` with optional `(required)` and
+/// `(default: <value>)` markers anywhere in the description.
+pub fn parse_query_param(trimmed: &str, span: Span) -> Result<QueryParamDoc, ParseError> {
+    let Some((name, rest)) = trimmed.split_once(':') else {
+        return Err(ParseError::with_span(
+            "Invalid query parameter format. Expected: <name>: <description>",
+            span,
+        ));
+    };
+
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid query parameter name '{name}'\n\
+                 help: parameter names cannot be empty or contain whitespace\n\
+                 note: format is '<name>: <description>'"
+            ),
+            span,
+        ));
+    }
+
+    let mut description = rest.to_string();
+
+    let required = description.contains("(required)");
+    if required {
+        description = description.replacen("(required)", "", 1);
+    }
+
+    let mut default = None;
+    if let Some(start) = description.find("(default:") {
+        // Find the matching closing paren so values like `(default: (1, 2))` work
+        let mut depth = 0;
+        let mut end = None;
+        for (byte_idx, ch) in description[start..].char_indices() {
+            match ch {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(start + byte_idx);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let Some(end) = end else {
+            return Err(ParseError::with_span(
+                "Unclosed '(default: ...)' marker\n\
+                 help: close the marker with ')'",
+                span,
+            ));
+        };
+
+        let value = description[start + "(default:".len()..end]
+            .trim()
+            .replace("\\\"", "\"");
+        if value.is_empty() {
+            return Err(ParseError::with_span(
+                "Empty value in '(default: ...)' marker\n\
+                 help: provide a default value, e.g. '(default: 20)'",
+                span,
+            ));
+        }
+
+        default = Some(value);
+        description.replace_range(start..=end, "");
+    }
+
+    Ok(QueryParamDoc {
+        name: name.to_string(),
+        description: description.split_whitespace().collect::<Vec<_>>().join(" "),
+        required,
+        default,
+        span,
+    })
+}
+
+/// Parse a request header line (for the `# Headers` section)
+///
+/// Format: `<Header-Name>: <type> - <description>` with an optional `(required)`
+/// marker after the type.
+pub fn parse_header(trimmed: &str, span: Span) -> Result<HeaderDoc, ParseError> {
+    let format_error = || {
+        ParseError::with_span(
+            "Invalid header format. Expected: <Header-Name>: <type> - <description>\n\
+             note: example 'X-Request-Id: String (required) - Correlation ID'",
+            span,
+        )
+    };
+
+    let (name, rest) = trimmed.split_once(':').ok_or_else(format_error)?;
+    let (type_part, description) = rest.split_once(" - ").ok_or_else(format_error)?;
+
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+    {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid header name '{name}'\n\
+                 help: header names may only contain token characters: letters, digits and !#$%&'*+-.^_`|~\n\
+                 note: example 'X-Request-Id'"
+            ),
+            span,
+        ));
+    }
+
+    let required = type_part.contains("(required)");
+    let type_str = type_part.replacen("(required)", "", 1);
+    let type_str = type_str.trim();
+
+    let header_type: TokenStream = type_str
+        .parse()
+        .ok()
+        .filter(|_| syn::parse_str::<syn::Type>(type_str).is_ok())
+        .ok_or_else(|| {
+            ParseError::with_span(
+                format!(
+                    "Invalid header type '{type_str}'\n\
+                     help: header type must be a valid Rust type\n\
+                     note: common types: String, u64, Uuid"
+                ),
+                span,
+            )
+        })?;
+
+    let description = description.trim();
+    if description.is_empty() {
+        return Err(ParseError::with_span(
+            "Missing description for header\n\
+             help: add a description after the header type\n\
+             note: format is '<Header-Name>: <type> - <description>'",
+            span,
+        ));
+    }
+
+    // Point type errors (unknown type, missing JsonSchema impl) at the doc line
+    let header_type = respan(header_type, span);
+
+    Ok(HeaderDoc {
+        name: name.to_string(),
+        header_type,
+        description: description.to_string(),
+        required,
         span,
     })
 }
@@ -163,7 +423,12 @@ pub fn parse_security(trimmed: &str, span: Span) -> Result<String, ParseError> {
 /// Parse @id annotation
 pub fn parse_id(trimmed: &str, span: Span) -> Result<String, ParseError> {
     let id = parse_simple_annotation!(trimmed, span, "id", "<operation_id>", "getUserById");
+    validate_operation_id(&id, span)?;
+    Ok(id)
+}
 
+/// Validate an operation ID from `@id` or `#[rovo(id = "...")]`
+pub fn validate_operation_id(id: &str, span: Span) -> Result<(), ParseError> {
     if !id.chars().all(|c| c.is_alphanumeric() || c == '_') {
         return Err(ParseError::with_span(
             format!(
@@ -175,7 +440,25 @@ pub fn parse_id(trimmed: &str, span: Span) -> Result<String, ParseError> {
         ));
     }
 
-    Ok(id)
+    Ok(())
+}
+
+/// Set the span of every token, including those nested in groups
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(group) => {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                TokenTree::Group(respanned)
+            }
+            mut tt => {
+                tt.set_span(span);
+                tt
+            }
+        })
+        .collect()
 }
 
 /// Validate HTTP status code
@@ -403,6 +686,203 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // Tests for request body helpers
+
+    #[test]
+    fn request_body_example_valid() {
+        let result = parse_request_body_example("Todo { done: false }", Span::call_site());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn request_body_example_empty() {
+        let result = parse_request_body_example("  ", Span::call_site());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Empty request body example"));
+    }
+
+    #[test]
+    fn content_type_valid() {
+        let result = parse_content_type(" application/merge-patch+json", Span::call_site());
+        assert_eq!(result.unwrap(), "application/merge-patch+json");
+    }
+
+    #[test]
+    fn content_type_rejects_missing_subtype() {
+        assert!(parse_content_type("json", Span::call_site()).is_err());
+        assert!(parse_content_type("application/", Span::call_site()).is_err());
+        assert!(parse_content_type("text/plain charset", Span::call_site()).is_err());
+    }
+
+    // Tests for split_example_key
+
+    #[test]
+    fn example_key_unnamed() {
+        let (status, name, code) = split_example_key("200: vec![]", Span::call_site())
+            .unwrap()
+            .unwrap();
+        assert_eq!(status, "200");
+        assert!(name.is_none());
+        assert_eq!(code, " vec![]");
+    }
+
+    #[test]
+    fn example_key_named() {
+        let (status, name, code) =
+            split_example_key("200 \\\"empty: list\\\": vec![]", Span::call_site())
+                .unwrap()
+                .unwrap();
+        assert_eq!(status, "200");
+        assert_eq!(name.as_deref(), Some("empty: list"));
+        assert_eq!(code, " vec![]");
+    }
+
+    #[test]
+    fn example_key_ignores_other_lines() {
+        assert!(split_example_key("Some text", Span::call_site())
+            .unwrap()
+            .is_none());
+        assert!(split_example_key("}", Span::call_site()).unwrap().is_none());
+    }
+
+    #[test]
+    fn example_key_rejects_malformed_names() {
+        let unclosed = split_example_key("200 \\\"empty: vec![]", Span::call_site());
+        assert!(unclosed.unwrap_err().to_string().contains("Unclosed"));
+
+        let empty = split_example_key("200 \\\"\\\": vec![]", Span::call_site());
+        assert!(empty
+            .unwrap_err()
+            .to_string()
+            .contains("Empty example name"));
+
+        let no_colon = split_example_key("200 \\\"empty\\\" vec![]", Span::call_site());
+        assert!(no_colon.unwrap_err().to_string().contains("Missing ':'"));
+    }
+
+    // Tests for parse_query_param
+
+    #[test]
+    fn query_param_plain() {
+        let result = parse_query_param("page: Page number", Span::call_site()).unwrap();
+        assert_eq!(result.name, "page");
+        assert_eq!(result.description, "Page number");
+        assert!(!result.required);
+        assert!(result.default.is_none());
+    }
+
+    #[test]
+    fn query_param_required_marker() {
+        let result = parse_query_param("q: Search term (required)", Span::call_site()).unwrap();
+        assert_eq!(result.description, "Search term");
+        assert!(result.required);
+    }
+
+    #[test]
+    fn query_param_default_marker() {
+        let result =
+            parse_query_param("limit: Page size (default: 20)", Span::call_site()).unwrap();
+        assert_eq!(result.description, "Page size");
+        assert_eq!(result.default.as_deref(), Some("20"));
+    }
+
+    #[test]
+    fn query_param_both_markers() {
+        let result = parse_query_param(
+            "sort: (required) Sort order (default: \\\"asc\\\")",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(result.description, "Sort order");
+        assert!(result.required);
+        assert_eq!(result.default.as_deref(), Some("\"asc\""));
+    }
+
+    #[test]
+    fn query_param_requires_colon() {
+        let result = parse_query_param("page Page number", Span::call_site());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid query parameter format"));
+    }
+
+    #[test]
+    fn query_param_rejects_empty_default() {
+        let result = parse_query_param("page: Page (default: )", Span::call_site());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Empty value"));
+    }
+
+    #[test]
+    fn query_param_rejects_unclosed_default() {
+        let result = parse_query_param("page: Page (default: 1", Span::call_site());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unclosed"));
+    }
+
+    // Tests for parse_header
+
+    #[test]
+    fn header_valid() {
+        let result =
+            parse_header("X-Request-Id: String - Correlation ID", Span::call_site()).unwrap();
+        assert_eq!(result.name, "X-Request-Id");
+        assert_eq!(result.header_type.to_string(), "String");
+        assert_eq!(result.description, "Correlation ID");
+        assert!(!result.required);
+    }
+
+    #[test]
+    fn header_required_marker() {
+        let result = parse_header(
+            "X-Tenant-Id: Uuid (required) - Tenant the request acts on",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(result.header_type.to_string(), "Uuid");
+        assert!(result.required);
+    }
+
+    #[test]
+    fn header_requires_description() {
+        let result = parse_header("If-Match: String", Span::call_site());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid header format"));
+    }
+
+    #[test]
+    fn header_rejects_invalid_name() {
+        let result = parse_header("X Tenant: String - Tenant", Span::call_site());
+        assert!(result.is_err());
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Invalid header name"));
+        assert!(message.contains("token characters"));
+    }
+
+    #[test]
+    fn header_accepts_token_characters() {
+        let header = parse_header("X-Tenant_Id.v2: String - Tenant", Span::call_site()).unwrap();
+        assert_eq!(header.name, "X-Tenant_Id.v2");
+    }
+
+    #[test]
+    fn header_rejects_invalid_type() {
+        let result = parse_header("If-Match: Vec< - Entity tag", Span::call_site());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid header type"));
+    }
+
     // Additional edge case tests for simple annotations
 
     #[test]
@@ -0,0 +1,337 @@
+use proc_macro2::{Span, TokenStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, Lit, Meta, Token};
+
+use super::annotations::validate_operation_id;
+use super::error::ParseError;
+use super::types::{DocInfo, IdStyle, WarningKind};
+
+const SUPPORTED_ARGUMENTS: &str =
+    "tag = \"...\", security = \"...\", id = \"...\", id_style = \"...\", hidden, allow(...)";
+
+/// Metadata given as `#[rovo(...)]` attribute arguments
+#[derive(Debug, Default)]
+pub struct RovoAttributes {
+    pub tags: Vec<String>,
+    pub security_requirements: Vec<String>,
+    /// Operation ID with the span of its string literal
+    pub operation_id: Option<(String, Span)>,
+    /// Style of the default operation ID, overriding `rovo.toml`
+    pub id_style: Option<IdStyle>,
+    pub hidden: bool,
+    /// Warnings silenced with `allow(...)`
+    pub allowed_warnings: Vec<WarningKind>,
+}
+
+impl RovoAttributes {
+    /// Merge the attribute metadata into the metadata from the doc comments
+    ///
+    /// Tags and security requirements are combined without duplicates. An
+    /// operation ID may be given in both places only if they agree.
+    pub fn merge_into(self, doc_info: &mut DocInfo) -> Result<(), ParseError> {
+        for tag in self.tags {
+            if !doc_info.tags.contains(&tag) {
+                doc_info.tags.push(tag);
+            }
+        }
+
+        for scheme in self.security_requirements {
+            if !doc_info.security_requirements.contains(&scheme) {
+                doc_info.security_requirements.push(scheme);
+            }
+        }
+
+        if let Some((id, span)) = self.operation_id {
+            match &doc_info.operation_id {
+                Some(doc_id) if *doc_id != id => {
+                    return Err(ParseError::with_span(
+                        format!(
+                            "Conflicting operation IDs: #[rovo(id = \"{id}\")] and '@id {doc_id}'\n\
+                             help: remove one of them, or make them match"
+                        ),
+                        span,
+                    ));
+                }
+                _ => doc_info.operation_id = Some(id),
+            }
+        }
+
+        if let Some(style) = self.id_style {
+            doc_info.id_style = style;
+        }
+
+        doc_info.hidden |= self.hidden;
+
+        Ok(())
+    }
+}
+
+/// Parse the arguments of `#[rovo(...)]`
+pub fn parse_rovo_attributes(attr: TokenStream) -> Result<RovoAttributes, ParseError> {
+    let mut attributes = RovoAttributes::default();
+    if attr.is_empty() {
+        return Ok(attributes);
+    }
+
+    let args = syn::parse::Parser::parse2(Punctuated::<Meta, Token![,]>::parse_terminated, attr)
+        .map_err(|e| {
+            ParseError::with_span(
+                format!(
+                    "Invalid #[rovo(...)] arguments: {e}\n\
+                     note: supported arguments: {SUPPORTED_ARGUMENTS}"
+                ),
+                e.span(),
+            )
+        })?;
+
+    for meta in args {
+        let name = meta.path().get_ident().map(ToString::to_string);
+        match (name.as_deref(), &meta) {
+            (Some("hidden"), Meta::Path(_)) => attributes.hidden = true,
+            (Some("allow"), Meta::List(list)) => {
+                let names = list
+                    .parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)
+                    .map_err(|e| {
+                        ParseError::with_span(
+                            format!(
+                                "Invalid 'allow' argument in #[rovo(...)]: {e}\n\
+                                 help: expected allow(warning_name, ...)"
+                            ),
+                            e.span(),
+                        )
+                    })?;
+                for name in names {
+                    let kind = WarningKind::from_name(&name.to_string()).ok_or_else(|| {
+                        let known: Vec<&str> =
+                            WarningKind::ALL.iter().map(|kind| kind.name()).collect();
+                        ParseError::with_span(
+                            format!(
+                                "Unknown warning '{name}' in #[rovo(allow(...))]\n\
+                                 note: known warnings: {}",
+                                known.join(", ")
+                            ),
+                            name.span(),
+                        )
+                    })?;
+                    attributes.allowed_warnings.push(kind);
+                }
+            }
+            (Some("tag"), Meta::NameValue(nv)) => {
+                let (tag, _) = string_value(&nv.value, "tag")?;
+                attributes.tags.push(tag);
+            }
+            (Some("security"), Meta::NameValue(nv)) => {
+                let (scheme, _) = string_value(&nv.value, "security")?;
+                attributes.security_requirements.push(scheme);
+            }
+            (Some("id"), Meta::NameValue(nv)) => {
+                let (id, span) = string_value(&nv.value, "id")?;
+                if attributes.operation_id.is_some() {
+                    return Err(ParseError::with_span(
+                        "Duplicate 'id' argument in #[rovo(...)]\n\
+                         help: an operation can only have one ID",
+                        span,
+                    ));
+                }
+                validate_operation_id(&id, span)?;
+                attributes.operation_id = Some((id, span));
+            }
+            (Some("id_style"), Meta::NameValue(nv)) => {
+                let (name, span) = string_value(&nv.value, "id_style")?;
+                if attributes.id_style.is_some() {
+                    return Err(ParseError::with_span(
+                        "Duplicate 'id_style' argument in #[rovo(...)]",
+                        span,
+                    ));
+                }
+                let style = IdStyle::from_name(&name).ok_or_else(|| {
+                    ParseError::with_span(
+                        format!(
+                            "Unknown ID style '{name}' in #[rovo(...)]\n\
+                             note: known styles: {}",
+                            IdStyle::names()
+                        ),
+                        span,
+                    )
+                })?;
+                attributes.id_style = Some(style);
+            }
+            (Some(name @ ("tag" | "security" | "id" | "id_style")), _) => {
+                return Err(ParseError::with_span(
+                    format!(
+                        "Invalid '{name}' argument in #[rovo(...)]\n\
+                         help: expected {name} = \"...\""
+                    ),
+                    meta.span(),
+                ));
+            }
+            (Some("allow"), _) => {
+                return Err(ParseError::with_span(
+                    "Invalid 'allow' argument in #[rovo(...)]\n\
+                     help: expected allow(warning_name, ...)",
+                    meta.span(),
+                ));
+            }
+            (Some("hidden"), _) => {
+                return Err(ParseError::with_span(
+                    "Invalid 'hidden' argument in #[rovo(...)]\n\
+                     help: 'hidden' takes no value, use #[rovo(hidden)]",
+                    meta.span(),
+                ));
+            }
+            _ => {
+                let name = name.unwrap_or_else(|| {
+                    let path = &meta.path();
+                    quote::quote!(#path).to_string().replace(' ', "")
+                });
+                return Err(ParseError::with_span(
+                    format!(
+                        "Unknown #[rovo(...)] argument '{name}'\n\
+                         note: supported arguments: {SUPPORTED_ARGUMENTS}"
+                    ),
+                    meta.path().span(),
+                ));
+            }
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Extract a non-empty string literal from an argument value
+fn string_value(value: &Expr, name: &str) -> Result<(String, Span), ParseError> {
+    let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = value
+    else {
+        return Err(ParseError::with_span(
+            format!(
+                "Expected a string literal for '{name}'\n\
+                 help: use {name} = \"...\""
+            ),
+            value.span(),
+        ));
+    };
+
+    let text = lit.value();
+    if text.trim().is_empty() {
+        return Err(ParseError::with_span(
+            format!("Empty value for '{name}' in #[rovo(...)]"),
+            lit.span(),
+        ));
+    }
+
+    Ok((text.trim().to_string(), lit.span()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(attr: &str) -> Result<RovoAttributes, ParseError> {
+        parse_rovo_attributes(attr.parse().unwrap())
+    }
+
+    #[test]
+    fn parses_empty_arguments() {
+        let attributes = parse("").unwrap();
+        assert!(attributes.tags.is_empty());
+        assert!(attributes.operation_id.is_none());
+        assert!(!attributes.hidden);
+    }
+
+    #[test]
+    fn parses_all_arguments() {
+        let attributes =
+            parse(r#"tag = "users", tag = "admin", security = "bearer", id = "getUser", hidden"#)
+                .unwrap();
+        assert_eq!(attributes.tags, vec!["users", "admin"]);
+        assert_eq!(attributes.security_requirements, vec!["bearer"]);
+        assert_eq!(attributes.operation_id.unwrap().0, "getUser");
+        assert!(attributes.hidden);
+    }
+
+    #[test]
+    fn rejects_unknown_argument() {
+        let err = parse(r#"tags = "users""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown #[rovo(...)] argument 'tags'"));
+    }
+
+    #[test]
+    fn rejects_non_string_value() {
+        let err = parse("tag = users").unwrap_err();
+        assert!(err.to_string().contains("Expected a string literal"));
+    }
+
+    #[test]
+    fn rejects_value_on_hidden() {
+        let err = parse("hidden = true").unwrap_err();
+        assert!(err.to_string().contains("takes no value"));
+    }
+
+    #[test]
+    fn rejects_invalid_and_duplicate_ids() {
+        assert!(parse(r#"id = "get-user""#)
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid operation ID"));
+        assert!(parse(r#"id = "a", id = "b""#)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate 'id'"));
+    }
+
+    #[test]
+    fn parses_id_style() {
+        let attributes = parse(r#"id_style = "camel""#).unwrap();
+        assert_eq!(attributes.id_style, Some(IdStyle::Camel));
+
+        let err = parse(r#"id_style = "kebab""#).unwrap_err();
+        assert!(err.to_string().contains("Unknown ID style 'kebab'"));
+        assert!(err.to_string().contains("name, module, camel, tag"));
+    }
+
+    #[test]
+    fn parses_allowed_warnings() {
+        let attributes = parse("allow(missing_responses, unknown_sections)").unwrap();
+        assert_eq!(
+            attributes.allowed_warnings,
+            vec![WarningKind::MissingResponses, WarningKind::UnknownSections]
+        );
+
+        let err = parse("allow(missing_docs)").unwrap_err();
+        assert!(err.to_string().contains("Unknown warning 'missing_docs'"));
+    }
+
+    #[test]
+    fn merges_with_doc_metadata() {
+        let mut doc_info = DocInfo {
+            tags: vec!["users".to_string()],
+            operation_id: Some("getUser".to_string()),
+            ..DocInfo::default()
+        };
+        parse(r#"tag = "users", tag = "admin", id = "getUser", hidden"#)
+            .unwrap()
+            .merge_into(&mut doc_info)
+            .unwrap();
+        assert_eq!(doc_info.tags, vec!["users", "admin"]);
+        assert_eq!(doc_info.operation_id.as_deref(), Some("getUser"));
+        assert!(doc_info.hidden);
+    }
+
+    #[test]
+    fn rejects_conflicting_operation_ids() {
+        let mut doc_info = DocInfo {
+            operation_id: Some("getUser".to_string()),
+            ..DocInfo::default()
+        };
+        let err = parse(r#"id = "fetchUser""#)
+            .unwrap()
+            .merge_into(&mut doc_info)
+            .unwrap_err();
+        assert!(err.to_string().contains("Conflicting operation IDs"));
+    }
+}
@@ -5,6 +5,8 @@ use std::fmt;
 pub struct ParseError {
     message: String,
     span: Option<Span>,
+    /// Further errors reported together with this one
+    others: Vec<Self>,
 }
 
 impl ParseError {
@@ -12,6 +14,7 @@ impl ParseError {
         Self {
             message: message.into(),
             span: None,
+            others: Vec::new(),
         }
     }
 
@@ -19,12 +22,31 @@ impl ParseError {
         Self {
             message: message.into(),
             span: Some(span),
+            others: Vec::new(),
         }
     }
 
     pub const fn span(&self) -> Option<Span> {
         self.span
     }
+
+    /// Combine several errors into one so they can be reported together,
+    /// returning `None` if there are none
+    pub fn combine(errors: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let mut first = errors.next()?;
+        for mut error in errors {
+            let others = std::mem::take(&mut error.others);
+            first.others.push(error);
+            first.others.extend(others);
+        }
+        Some(first)
+    }
+
+    /// Iterate over this error and every error combined into it
+    pub fn iter(&self) -> impl Iterator<Item = &Self> {
+        std::iter::once(self).chain(&self.others)
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -52,17 +74,27 @@ mod tests {
         assert_eq!(error.to_string(), "test error");
     }
 
+    #[test]
+    fn combines_errors_in_order() {
+        let first = ParseError::combine([ParseError::new("a"), ParseError::new("b")]);
+        let combined =
+            ParseError::combine([first.unwrap(), ParseError::new("c")]).expect("non-empty");
+        let messages: Vec<String> = combined.iter().map(ToString::to_string).collect();
+        assert_eq!(messages, ["a", "b", "c"]);
+        assert!(ParseError::combine([]).is_none());
+    }
+
     #[test]
     fn display_format_works() {
         let error = ParseError::new("custom message");
-        let formatted = format!("{}", error);
+        let formatted = format!("{error}");
         assert_eq!(formatted, "custom message");
     }
 
     #[test]
     fn debug_format_works() {
         let error = ParseError::new("debug test");
-        let debug_str = format!("{:?}", error);
+        let debug_str = format!("{error:?}");
         assert!(debug_str.contains("ParseError"));
     }
 }
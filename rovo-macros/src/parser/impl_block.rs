@@ -0,0 +1,205 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Attribute, ImplItem, ItemImpl, Meta, Visibility};
+
+use super::ParseError;
+
+/// A `#[rovo]` method taken out of an `impl` block
+pub struct RovoMethod {
+    /// Arguments of the method's `#[rovo(...)]` attribute
+    pub attr: TokenStream,
+    /// The method without its `#[rovo]` attribute
+    pub tokens: TokenStream,
+    /// Visibility of the method, reused for its handler const
+    pub vis: Visibility,
+    /// Doc comment attributes of the method, copied onto its handler const
+    pub doc_attrs: Vec<Attribute>,
+}
+
+/// An `impl` block annotated with `#[rovo]`
+pub struct ImplBlock {
+    /// The block with all `#[rovo]` methods removed
+    pub item: ItemImpl,
+    /// The `#[rovo]` methods, in declaration order
+    pub methods: Vec<RovoMethod>,
+}
+
+/// Check whether the annotated item is an `impl` block rather than a function
+pub fn is_impl_block(input: &TokenStream) -> bool {
+    for token in input.clone() {
+        if let TokenTree::Ident(ident) = token {
+            if ident == "impl" {
+                return true;
+            }
+            if ident == "fn" {
+                return false;
+            }
+        }
+    }
+    false
+}
+
+/// Check whether an attribute is `#[rovo]`, `#[rovo(...)]` or `#[rovo::rovo]`
+fn is_rovo_attribute(attr: &Attribute) -> bool {
+    let segments: Vec<String> = attr
+        .path()
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    segments == ["rovo"] || segments == ["rovo", "rovo"]
+}
+
+/// Split an `impl` block into its `#[rovo]` methods and everything else
+pub fn parse_impl_block(attr: TokenStream, input: TokenStream) -> Result<ImplBlock, ParseError> {
+    if let Some(first) = attr.into_iter().next() {
+        return Err(ParseError::with_span(
+            "#[rovo] on an impl block does not take arguments\n\
+             help: pass them to the #[rovo(...)] attributes on the methods instead",
+            first.span(),
+        ));
+    }
+
+    let mut item: ItemImpl = syn::parse2(input).map_err(|e| {
+        ParseError::with_span(
+            format!("#[rovo] can only be used on functions and impl blocks: {e}"),
+            e.span(),
+        )
+    })?;
+
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(ParseError::with_span(
+            "#[rovo] cannot be used on trait impl blocks\n\
+             help: move the handlers into an inherent impl block (`impl Handlers { ... }`)",
+            path.span(),
+        ));
+    }
+
+    let mut methods = Vec::new();
+    let mut items = Vec::with_capacity(item.items.len());
+
+    for impl_item in std::mem::take(&mut item.items) {
+        let ImplItem::Fn(mut method) = impl_item else {
+            items.push(impl_item);
+            continue;
+        };
+
+        let Some(index) = method.attrs.iter().position(is_rovo_attribute) else {
+            items.push(ImplItem::Fn(method));
+            continue;
+        };
+
+        let rovo_attr = method.attrs.remove(index);
+        let attr = match rovo_attr.meta {
+            Meta::Path(_) => TokenStream::new(),
+            Meta::List(list) => list.tokens,
+            Meta::NameValue(name_value) => {
+                return Err(ParseError::with_span(
+                    "Expected #[rovo] or #[rovo(...)]",
+                    name_value.span(),
+                ));
+            }
+        };
+
+        if let Some(receiver) = method.sig.receiver() {
+            return Err(ParseError::with_span(
+                format!(
+                    "#[rovo] method '{}' cannot take `self`\n\
+                     help: handlers must be associated functions without a receiver",
+                    method.sig.ident
+                ),
+                receiver.span(),
+            ));
+        }
+
+        let doc_attrs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .cloned()
+            .collect();
+
+        methods.push(RovoMethod {
+            attr,
+            tokens: method.to_token_stream(),
+            vis: method.vis.clone(),
+            doc_attrs,
+        });
+    }
+
+    item.items = items;
+
+    Ok(ImplBlock { item, methods })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn detects_impl_blocks() {
+        assert!(is_impl_block(&quote! { impl Handlers { fn a() {} } }));
+        assert!(is_impl_block(
+            &quote! { #[doc = "x"] impl<T> Handlers<T> {} }
+        ));
+        assert!(!is_impl_block(
+            &quote! { async fn a() -> impl IntoApiResponse {} }
+        ));
+    }
+
+    #[test]
+    fn extracts_rovo_methods() {
+        let block = parse_impl_block(
+            TokenStream::new(),
+            quote! {
+                impl Handlers {
+                    #[rovo(tag = "users")]
+                    async fn list() {}
+
+                    fn helper() {}
+
+                    #[rovo]
+                    async fn get() {}
+                }
+            },
+        )
+        .unwrap();
+
+        assert_eq!(block.methods.len(), 2);
+        assert_eq!(
+            block.methods[0].attr.to_string(),
+            quote! { tag = "users" }.to_string()
+        );
+        assert!(block.methods[1].attr.is_empty());
+        assert!(!block.methods[0].tokens.to_string().contains("rovo"));
+        assert_eq!(block.item.items.len(), 1);
+    }
+
+    #[test]
+    fn rejects_receivers() {
+        let result = parse_impl_block(
+            TokenStream::new(),
+            quote! {
+                impl Handlers {
+                    #[rovo]
+                    async fn list(&self) {}
+                }
+            },
+        );
+        assert!(result.is_err_and(|e| e.to_string().contains("cannot take `self`")));
+    }
+
+    #[test]
+    fn rejects_trait_impls() {
+        let result = parse_impl_block(TokenStream::new(), quote! { impl Default for Handlers {} });
+        assert!(result.is_err_and(|e| e.to_string().contains("trait impl")));
+    }
+
+    #[test]
+    fn rejects_arguments_on_impl_block() {
+        let result = parse_impl_block(quote! { tag = "users" }, quote! { impl Handlers {} });
+        assert!(result.is_err_and(|e| e.to_string().contains("does not take arguments")));
+    }
+}
@@ -1,21 +1,40 @@
 mod annotations;
+mod attributes;
 mod error;
+mod impl_block;
+mod returns;
 mod tokens;
 pub mod types;
 
 pub use error::ParseError;
-pub use types::{DocInfo, FuncItem, PathParamDoc, PathParamInfo};
+pub use impl_block::{is_impl_block, parse_impl_block, ImplBlock};
+pub use types::{
+    DocInfo, FuncItem, HeaderDoc, IdStyle, PathParamDoc, PathParamInfo, QueryParamDoc,
+    QueryParamInfo, RequestBodyDoc, RequestBodyInfo,
+};
 
 use proc_macro2::{Span, TokenStream, TokenTree};
-use types::DocLine;
+use types::{DocLine, DocWarning, ExampleInfo, WarningKind};
 
-use crate::utils::find_closest_annotation;
+use crate::utils::{
+    find_closest_annotation, find_closest_section, is_primitive_tuple, is_primitive_type,
+};
 
 /// Special depth value indicating code block mode for multi-line examples
 const CODE_BLOCK_MODE: usize = usize::MAX - 1;
 
 /// Parse a function annotated with #[rovo]
-pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), ParseError> {
+pub fn parse_rovo_function(
+    attr: TokenStream,
+    input: TokenStream,
+) -> Result<(FuncItem, DocInfo), ParseError> {
+    // Problems are collected so they can all be reported in one go
+    let mut errors = Vec::new();
+
+    let attributes = attributes::parse_rovo_attributes(attr)
+        .map_err(|err| errors.push(err))
+        .ok();
+
     let tokens: Vec<TokenTree> = input.clone().into_iter().collect();
 
     // Extract doc comments, attributes, and function name
@@ -68,12 +87,64 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
     // Extract path parameter info from function signature
     let path_params = tokens::extract_path_info(&input);
 
+    // Extract query parameter info from function signature
+    let query_params = tokens::extract_query_info(&input);
+
+    // Extract request body type from function signature
+    let request_body = tokens::extract_request_body_info(&input);
+
+    // Infer the success response from the return type, for handlers without `# Responses`
+    let inferred_response = returns::infer_response(&input);
+    let error_type = returns::error_type(&input);
+
+    // Extract generic parameters, which the generated handle has to carry
+    let generics = extract_generics(&input).unwrap_or_else(|err| {
+        errors.push(err);
+        syn::Generics::default()
+    });
+
     // Parse doc comments
-    let mut doc_info = parse_doc_comments(&doc_lines)?;
+    let mut doc_info = match parse_doc_comments(&doc_lines) {
+        Ok(doc_info) => doc_info,
+        Err(err) => {
+            errors.push(err);
+            return Err(ParseError::combine(errors).expect("an error was just pushed"));
+        }
+    };
 
     // Set deprecated flag from Rust attribute
     doc_info.deprecated = is_deprecated;
 
+    // Warnings silenced through `#[rovo(allow(...))]`
+    let allowed_warnings = attributes
+        .as_ref()
+        .map(|attributes| attributes.allowed_warnings.clone())
+        .unwrap_or_default();
+
+    // Merge metadata from `#[rovo(...)]` arguments
+    let has_id_style = attributes
+        .as_ref()
+        .is_some_and(|attributes| attributes.id_style.is_some());
+    if let Some(attributes) = attributes {
+        if let Err(err) = attributes.merge_into(&mut doc_info) {
+            errors.push(err);
+        }
+    }
+
+    // Without an ID or ID style of its own, the handler uses the crate-wide style
+    if doc_info.operation_id.is_none() && !has_id_style {
+        match crate::config::load() {
+            Ok(Some((path, config))) => {
+                if let Some(style) = config.id_style {
+                    doc_info.id_style = style;
+                    doc_info.config_file = Some(path.to_string_lossy().into_owned());
+                }
+            }
+            Ok(None) => {}
+            Err(err) => errors.push(err),
+        }
+    }
+
     // Validate that documented path parameters match function signature bindings
     if !doc_info.path_params.is_empty() {
         if let Some(ref sig_params) = path_params {
@@ -82,7 +153,7 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
                 for doc_param in &doc_info.path_params {
                     if !sig_params.bindings.contains(&doc_param.name) {
                         let bindings_list = sig_params.bindings.join(", ");
-                        return Err(ParseError::with_span(
+                        errors.push(ParseError::with_span(
                             format!(
                                 "Documented path parameter '{}' does not match any parameter in function signature\n\
                                  help: found parameters: {}\n\
@@ -98,7 +169,7 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
         } else {
             // Documented path params but no Path<T> in signature
             let first_param = &doc_info.path_params[0];
-            return Err(ParseError::with_span(
+            errors.push(ParseError::with_span(
                 format!(
                     "Documented path parameter '{}' but function has no Path<T> extractor\n\
                      help: add a Path<T> parameter to your function signature",
@@ -109,30 +180,195 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
         }
     }
 
+    // `Query<T>` deserializes the query string as a map, which a primitive `T` can't
+    // be built from, so the extractor would reject every request
+    if let Some(sig_params) = query_params.as_ref().filter(|p| {
+        !p.is_struct_pattern
+            && if p.inner_type.starts_with('(') {
+                is_primitive_tuple(&p.inner_type)
+            } else {
+                is_primitive_type(&p.inner_type)
+            }
+    }) {
+        errors.push(ParseError::with_span(
+            format!(
+                "Query<{}> cannot be extracted from a query string\n\
+                 help: use a struct with #[derive(Deserialize, JsonSchema)] whose fields are the query parameters, \
+                 e.g. `Query(Params {{ {} }}): Query<Params>`\n\
+                 note: Query<T> deserializes the query string as a map of names to values",
+                sig_params.inner_type,
+                sig_params.bindings.join(", ")
+            ),
+            tokens::extractor_type_span(&input, "Query").unwrap_or_else(|| func_name.span()),
+        ));
+    }
+
+    // Validate that documented query parameters match function signature bindings
+    if !doc_info.query_params.is_empty() {
+        if let Some(ref sig_params) = query_params {
+            // Names are only known for destructured structs; an opaque binding like
+            // `Query(params): Query<ListParams>` is matched by aide at runtime
+            if sig_params.is_struct_pattern {
+                for doc_param in &doc_info.query_params {
+                    if !sig_params.bindings.contains(&doc_param.name) {
+                        let bindings_list = sig_params.bindings.join(", ");
+                        errors.push(ParseError::with_span(
+                            format!(
+                                "Documented query parameter '{}' does not match any parameter in function signature\n\
+                                 help: found parameters: {}\n\
+                                 note: parameter names in # Query Parameters must match the fields destructured in Query(...)",
+                                doc_param.name,
+                                bindings_list
+                            ),
+                            doc_param.span,
+                        ));
+                    }
+                }
+            }
+        } else {
+            // Documented query params but no Query<T> in signature
+            let first_param = &doc_info.query_params[0];
+            errors.push(ParseError::with_span(
+                format!(
+                    "Documented query parameter '{}' but function has no Query<T> extractor\n\
+                     help: add a Query<T> parameter to your function signature",
+                    first_param.name
+                ),
+                first_param.span,
+            ));
+        }
+    }
+
+    // A request body example is type-checked against the body extractor's type
+    if let Some(body_doc) = &doc_info.request_body {
+        if body_doc.example.is_some() && request_body.is_none() {
+            errors.push(ParseError::with_span(
+                "Documented request body example but function has no Json<T> or Form<T> extractor\n\
+                 help: add a Json<T> or Form<T> parameter to your function signature\n\
+                 note: the example is type-checked against T",
+                body_doc.example_span,
+            ));
+        }
+    }
+
+    if let Some(err) = ParseError::combine(errors) {
+        return Err(err);
+    }
+
+    // Hidden operations are not in the spec, so their responses do not matter
+    if doc_info.responses.is_empty() && inferred_response.is_none() && !doc_info.hidden {
+        doc_info.warnings.push(DocWarning {
+            kind: WarningKind::MissingResponses,
+            message: format!(
+                "handler '{func_name}' documents no responses; add a '# Responses' section \
+                 or silence with #[rovo(allow(missing_responses))]"
+            ),
+            span: func_name.span(),
+        });
+    }
+
+    // Primitive path bindings are named parameters that deserve a description
+    if let Some(sig_params) = path_params.as_ref().filter(|p| {
+        !p.is_struct_pattern
+            && if p.inner_type.starts_with('(') {
+                is_primitive_tuple(&p.inner_type)
+            } else {
+                is_primitive_type(&p.inner_type)
+            }
+    }) {
+        for binding in &sig_params.bindings {
+            if !doc_info.path_params.iter().any(|doc| doc.name == *binding) {
+                doc_info.warnings.push(DocWarning {
+                    kind: WarningKind::UndocumentedPathParams,
+                    message: format!(
+                        "path parameter '{binding}' of handler '{func_name}' is not documented; \
+                         add it to '# Path Parameters' or silence with \
+                         #[rovo(allow(undocumented_path_params))]"
+                    ),
+                    span: func_name.span(),
+                });
+            }
+        }
+    }
+
+    // A `HeaderMap` hides which headers are read, so only `# Headers` can tell
+    if doc_info.headers.is_empty() {
+        if let Some(span) = tokens::header_map_span(&input) {
+            doc_info.warnings.push(DocWarning {
+                kind: WarningKind::UndocumentedHeaders,
+                message: format!(
+                    "handler '{func_name}' reads a HeaderMap but documents no headers; \
+                     add a '# Headers' section or silence with \
+                     #[rovo(allow(undocumented_headers))]"
+                ),
+                span,
+            });
+        }
+    }
+
+    doc_info
+        .warnings
+        .retain(|warning| !allowed_warnings.contains(&warning.kind));
+
     let func_item = FuncItem {
         name: func_name,
         tokens: input,
         state_type,
         path_params,
+        query_params,
+        request_body,
+        generics,
+        inferred_response,
+        error_type,
     };
 
     Ok((func_item, doc_info))
 }
 
+/// Extract the generics of the handler function
+///
+/// Lifetime parameters are rejected: they cannot be named in the turbofish the
+/// generated handle uses to refer to the function.
+fn extract_generics(input: &TokenStream) -> Result<syn::Generics, ParseError> {
+    let Ok(func) = syn::parse2::<syn::ImplItemFn>(input.clone()) else {
+        return Ok(syn::Generics::default());
+    };
+
+    if let Some(lifetime) = func.sig.generics.lifetimes().next() {
+        return Err(ParseError::with_span(
+            "#[rovo] handlers cannot have lifetime parameters\n\
+             help: handlers must be 'static; use owned types in the signature",
+            lifetime.lifetime.apostrophe,
+        ));
+    }
+
+    Ok(func.sig.generics)
+}
+
+/// Accumulated state while walking the doc comment lines of a handler
+#[derive(Default)]
+struct DocParser {
+    doc_info: DocInfo,
+    description_lines: Vec<String>,
+    in_description: bool,
+    title_set: bool,
+    current_section: Option<&'static str>,
+    pending_response: Option<(u16, String, String, Span)>, // (status, type, desc, span)
+    pending_response_headers: Vec<HeaderDoc>,
+    pending_example: Option<(u16, Option<String>, String, Span, usize)>, // (status, name, code, span, depth)
+    pending_body_example: Option<(String, Span, usize)>,                 // (code, span, depth)
+    /// Problems found so far; parsing carries on past them so they can all be reported at once
+    errors: Vec<ParseError>,
+}
+
 /// Parse doc comments and extract documentation info
-#[allow(clippy::cognitive_complexity)]
+///
+/// Every malformed line is reported, not just the first one.
 fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
-    let mut doc_info = DocInfo::default();
-    let mut description_lines = Vec::new();
-    let mut in_description = false;
-    let mut title_set = false;
-    let mut current_section: Option<&str> = None;
-    let mut pending_response: Option<(u16, String, String, Span)> = None; // (status, type, desc, span)
-    let mut pending_example: Option<(u16, String, Span, usize)> = None; // (status, code, span, depth)
+    let mut parser = DocParser::default();
 
     for doc_line in lines {
         let trimmed = doc_line.text.trim();
-        let span = doc_line.span;
 
         // Check for @rovo-ignore first (location-independent)
         if trimmed == "@rovo-ignore" {
@@ -142,41 +378,136 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
         // Check if we're starting a markdown section
         if trimmed.starts_with("# ") {
             // Finalize any pending multi-line content
-            if let Some((status, type_str, desc, sp)) = pending_response.take() {
-                let response_info =
-                    annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
-                doc_info.responses.push(response_info);
-            }
-            if let Some((status, code, sp, _)) = pending_example.take() {
-                let example_info = annotations::parse_example_from_parts(status, &code, sp)?;
-                doc_info.examples.push(example_info);
-            }
+            parser.finish_pending();
 
             let section_name = trimmed.trim_start_matches("# ").trim();
-            current_section = match section_name {
+            parser.current_section = match section_name {
                 "Responses" => Some("responses"),
                 "Examples" => Some("examples"),
                 "Metadata" => Some("metadata"),
                 "Path Parameters" => Some("path_parameters"),
-                _ => None, // Unknown section - ignore
+                "Query Parameters" => Some("query_parameters"),
+                "Headers" => Some("headers"),
+                "Request Body" => Some("request_body"),
+                _ => {
+                    // Unknown section - its content is ignored, which is worth a warning
+                    parser.warn_unknown_section(section_name, doc_line.span);
+                    None
+                }
             };
             continue;
         }
 
-        match current_section {
+        let result = parser.parse_line(doc_line);
+        parser.record(result);
+    }
+
+    // Finalize any remaining pending content
+    parser.finish_pending();
+    parser.finish()
+}
+
+impl DocParser {
+    /// Warn about a `# Section` header whose content rovo ignores
+    fn warn_unknown_section(&mut self, section_name: &str, span: Span) {
+        let suggestion = find_closest_section(section_name)
+            .map(|section| format!(" (did you mean '# {section}'?)"))
+            .unwrap_or_default();
+        self.doc_info.warnings.push(DocWarning {
+            kind: WarningKind::UnknownSections,
+            message: format!(
+                "rovo ignores the unknown doc section '# {section_name}'{suggestion}; \
+                 silence with #[rovo(allow(unknown_sections))]"
+            ),
+            span,
+        });
+    }
+
+    /// Keep the error of a failed step for later reporting
+    fn record(&mut self, result: Result<(), ParseError>) {
+        if let Err(err) = result {
+            self.errors.push(err);
+        }
+    }
+
+    /// Finalize all pending multi-line content
+    fn finish_pending(&mut self) {
+        let result = self.finish_response();
+        self.record(result);
+        let result = self.finish_example();
+        self.record(result);
+        if let Some((code, sp, _)) = self.pending_body_example.take() {
+            let result = finalize_body_example(&mut self.doc_info, &code, sp);
+            self.record(result);
+        }
+    }
+
+    /// Finalize the pending response together with its headers
+    fn finish_response(&mut self) -> Result<(), ParseError> {
+        let headers = std::mem::take(&mut self.pending_response_headers);
+        if let Some((status, type_str, desc, sp)) = self.pending_response.take() {
+            let mut response_info =
+                annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
+            response_info.headers = headers;
+            self.doc_info.responses.push(response_info);
+        }
+        Ok(())
+    }
+
+    /// Finalize the pending (multi-line) example
+    fn finish_example(&mut self) -> Result<(), ParseError> {
+        if let Some((status, name, code, sp, _)) = self.pending_example.take() {
+            let mut example_info = annotations::parse_example_from_parts(status, &code, sp)?;
+            example_info.name = name;
+            self.doc_info.examples.push(example_info);
+        }
+        Ok(())
+    }
+
+    /// Parse a single line of the current section
+    #[allow(clippy::cognitive_complexity)]
+    fn parse_line(&mut self, doc_line: &DocLine) -> Result<(), ParseError> {
+        let trimmed = doc_line.text.trim();
+        let span = doc_line.span;
+
+        match self.current_section {
             Some("responses") if !trimmed.is_empty() => {
+                // Indented `header Name: Type - description` lines belong to the
+                // response above them
+                if let Some(header_line) = response_header_line(&doc_line.text) {
+                    let Some((status, ..)) = self.pending_response else {
+                        return Err(ParseError::with_span(
+                            "Response header must follow a response line\n\
+                             help: place indented 'header' lines directly below '<status>: <type> - <description>'",
+                            span,
+                        ));
+                    };
+                    let header = annotations::parse_header(header_line, span)?;
+                    if self
+                        .pending_response_headers
+                        .iter()
+                        .any(|h| h.name.eq_ignore_ascii_case(&header.name))
+                    {
+                        return Err(ParseError::with_span(
+                            format!(
+                                "Header '{}' is documented more than once for response {}\n\
+                                 note: header names are case-insensitive",
+                                header.name, status
+                            ),
+                            span,
+                        ));
+                    }
+                    self.pending_response_headers.push(header);
+                    return Ok(());
+                }
+
                 // Check if this line starts a new response or continues the previous one
                 if let Some(colon_pos) = trimmed.find(':') {
                     let before_colon = &trimmed[..colon_pos];
                     if before_colon.chars().all(|c| c.is_ascii_digit()) {
                         // This is a new response line
                         // First, finalize any pending response
-                        if let Some((status, type_str, desc, sp)) = pending_response.take() {
-                            let response_info = annotations::parse_response_from_parts(
-                                &type_str, status, &desc, sp,
-                            )?;
-                            doc_info.responses.push(response_info);
-                        }
+                        self.finish_response()?;
 
                         // Parse the new response line
                         let status_code = before_colon.parse::<u16>().map_err(|_| {
@@ -190,19 +521,20 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                         if let Some(dash_pos) = after_colon.find(" - ") {
                             let type_str = after_colon[..dash_pos].trim().to_string();
                             let description = after_colon[dash_pos + 3..].trim().to_string();
-                            pending_response = Some((status_code, type_str, description, span));
+                            self.pending_response =
+                                Some((status_code, type_str, description, span));
                         } else {
                             return Err(ParseError::with_span(
                                 "Invalid response format. Expected: <status>: <type> - <description>",
                                 span,
                             ));
                         }
-                    } else if let Some((_, _, ref mut desc, _)) = pending_response {
+                    } else if let Some((_, _, ref mut desc, _)) = self.pending_response {
                         // Continuation line for description
                         desc.push(' ');
                         desc.push_str(trimmed);
                     }
-                } else if let Some((_, _, ref mut desc, _)) = pending_response {
+                } else if let Some((_, _, ref mut desc, _)) = self.pending_response {
                     // Continuation line for description (no colon)
                     desc.push(' ');
                     desc.push_str(trimmed);
@@ -210,106 +542,77 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
             }
             Some("examples") if !trimmed.is_empty() => {
                 // Check if we have a pending example that needs more lines
-                if let Some((status, ref mut code, sp, ref mut depth)) = pending_example {
-                    if *depth == CODE_BLOCK_MODE {
-                        // In code block mode - looking for closing backticks
-                        if trimmed == "```" && !code.is_empty() {
-                            // Found closing backticks, finalize the example
-                            let final_code = code.clone();
-                            let example_info =
-                                annotations::parse_example_from_parts(status, &final_code, sp)?;
-                            doc_info.examples.push(example_info);
-                            pending_example = None;
-                        } else if code.is_empty()
-                            && (trimmed == "```" || trimmed == "```rust" || trimmed == "```rs")
-                        {
-                            // Opening backticks on their own line - skip
-                        } else {
-                            // Regular content line in code block
-                            if !code.is_empty() {
-                                code.push('\n');
-                            }
-                            code.push_str(trimmed);
-                        }
+                if let Some((_, _, ref mut code, _, ref mut depth)) = self.pending_example {
+                    if continue_example(code, depth, trimmed) {
+                        self.finish_example()?;
+                    }
+                } else if let Some((status_str, name, rest)) =
+                    annotations::split_example_key(trimmed, span)?
+                {
+                    // This is a new example line
+                    let status_code = status_str.parse::<u16>().map_err(|_| {
+                        ParseError::with_span(format!("Invalid status code '{status_str}'"), span)
+                    })?;
+
+                    let (code, depth) = start_example(rest.trim());
+                    if depth == 0 && !code.is_empty() {
+                        // Single-line example, process immediately
+                        let mut example_info =
+                            annotations::parse_example_from_parts(status_code, &code, span)?;
+                        example_info.name = name;
+                        self.doc_info.examples.push(example_info);
                     } else {
-                        // Check if first line is switching to code block mode
-                        if code.is_empty()
-                            && (trimmed == "```" || trimmed == "```rust" || trimmed == "```rs")
-                        {
-                            // Switch to code block mode
-                            *depth = CODE_BLOCK_MODE;
-                        } else {
-                            // Normal bracket/brace tracking mode
-                            // Add this line to the pending example
-                            if !code.is_empty() {
-                                code.push('\n');
-                            }
-                            code.push_str(trimmed);
-
-                            // Update bracket/brace depth
-                            for ch in trimmed.chars() {
-                                match ch {
-                                    '{' | '[' | '(' => *depth += 1,
-                                    '}' | ']' | ')' => *depth = depth.saturating_sub(1),
-                                    _ => {}
-                                }
-                            }
-
-                            // If depth is 0 and we have meaningful content, finalize it
-                            if *depth == 0 && !code.trim().is_empty() {
-                                let final_code = code.clone();
-                                let example_info =
-                                    annotations::parse_example_from_parts(status, &final_code, sp)?;
-                                doc_info.examples.push(example_info);
-                                pending_example = None;
-                            }
+                        // Multi-line example, store for continuation
+                        self.pending_example = Some((status_code, name, code, span, depth));
+                    }
+                }
+            }
+            Some("request_body") if !trimmed.is_empty() => {
+                let body = self
+                    .doc_info
+                    .request_body
+                    .get_or_insert_with(|| RequestBodyDoc::new(span));
+
+                if let Some((ref mut code, sp, ref mut depth)) = self.pending_body_example {
+                    if continue_example(code, depth, trimmed) {
+                        let code = std::mem::take(code);
+                        self.pending_body_example = None;
+                        body.example = Some(annotations::parse_request_body_example(&code, sp)?);
+                        body.example_span = sp;
+                    }
+                } else if let Some(value) = strip_key(trimmed, "Content-Type:") {
+                    body.content_type = Some(annotations::parse_content_type(value, span)?);
+                } else if let Some(value) = strip_key(trimmed, "Example:") {
+                    if body.example.is_some() {
+                        return Err(ParseError::with_span(
+                            "Request body example is documented more than once\n\
+                             help: keep a single 'Example: <expression>' line",
+                            span,
+                        ));
+                    }
+                    let (code, depth) = start_example(value.trim());
+                    if depth == 0 && !code.is_empty() {
+                        body.example = Some(annotations::parse_request_body_example(&code, span)?);
+                        body.example_span = span;
+                    } else {
+                        self.pending_body_example = Some((code, span, depth));
+                    }
+                } else {
+                    // Free-form description line, optionally carrying a marker
+                    let mut line = trimmed.to_string();
+                    for (marker, required) in [("(optional)", false), ("(required)", true)] {
+                        if line.contains(marker) {
+                            line = line.replacen(marker, "", 1);
+                            body.required = Some(required);
                         }
                     }
-                } else if let Some(colon_pos) = trimmed.find(':') {
-                    let before_colon = &trimmed[..colon_pos];
-                    if before_colon.chars().all(|c| c.is_ascii_digit()) {
-                        // This is a new example line
-                        let status_code = before_colon.parse::<u16>().map_err(|_| {
-                            ParseError::with_span(
-                                format!("Invalid status code '{before_colon}'"),
-                                span,
-                            )
-                        })?;
-
-                        let code = trimmed[colon_pos + 1..].trim().to_string();
-
-                        // Check if code starts with triple backticks (code block on same line)
-                        if code == "```" || code == "```rust" || code == "```rs" {
-                            // Start code block mode
-                            pending_example =
-                                Some((status_code, String::new(), span, CODE_BLOCK_MODE));
-                        } else if code.is_empty() {
-                            // Store pending example with empty code, depth 0 (will accumulate on next lines)
-                            pending_example = Some((status_code, String::new(), span, 0));
-                        } else {
-                            // Calculate bracket/brace depth
-                            let mut depth: usize = 0;
-                            for ch in code.chars() {
-                                match ch {
-                                    '{' | '[' | '(' => depth += 1,
-                                    '}' | ']' | ')' => depth = depth.saturating_sub(1),
-                                    _ => {}
-                                }
-                            }
-
-                            if depth == 0 {
-                                // Single-line example, process immediately
-                                let example_info = annotations::parse_example_from_parts(
-                                    status_code,
-                                    &code,
-                                    span,
-                                )?;
-                                doc_info.examples.push(example_info);
-                            } else {
-                                // Multi-line example, store for continuation
-                                pending_example = Some((status_code, code, span, depth));
-                            }
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        let description = body.description.get_or_insert_with(String::new);
+                        if !description.is_empty() {
+                            description.push(' ');
                         }
+                        description.push_str(line);
                     }
                 }
             }
@@ -317,15 +620,15 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                 // Parse annotations in metadata section
                 if trimmed.starts_with("@tag") {
                     let tag = annotations::parse_tag(trimmed, span)?;
-                    doc_info.tags.push(tag);
+                    self.doc_info.tags.push(tag);
                 } else if trimmed.starts_with("@security") {
                     let scheme = annotations::parse_security(trimmed, span)?;
-                    doc_info.security_requirements.push(scheme);
+                    self.doc_info.security_requirements.push(scheme);
                 } else if trimmed.starts_with("@id") {
                     let id = annotations::parse_id(trimmed, span)?;
-                    doc_info.operation_id = Some(id);
+                    self.doc_info.operation_id = Some(id);
                 } else if trimmed == "@hidden" {
-                    doc_info.hidden = true;
+                    self.doc_info.hidden = true;
                 } else if trimmed.starts_with('@') {
                     // Unknown annotation in metadata section
                     let annotation = trimmed.split_whitespace().next().unwrap_or(trimmed);
@@ -356,72 +659,248 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                 if let Some(colon_pos) = trimmed.find(':') {
                     let name = trimmed[..colon_pos].trim().to_string();
                     let description = trimmed[colon_pos + 1..].trim().to_string();
-                    doc_info.path_params.push(PathParamDoc {
+                    self.doc_info.path_params.push(PathParamDoc {
                         name,
                         description,
                         span,
                     });
                 }
             }
+            Some("query_parameters") if !trimmed.is_empty() => {
+                // Parse query parameter documentation
+                // Format: "name: description (required) (default: value)"
+                let query_param = annotations::parse_query_param(trimmed, span)?;
+                self.doc_info.query_params.push(query_param);
+            }
+            Some("headers") if !trimmed.is_empty() => {
+                // Parse request header documentation
+                // Format: "Header-Name: Type (required) - description"
+                let header = annotations::parse_header(trimmed, span)?;
+                if self
+                    .doc_info
+                    .headers
+                    .iter()
+                    .any(|h| h.name.eq_ignore_ascii_case(&header.name))
+                {
+                    return Err(ParseError::with_span(
+                        format!(
+                            "Header '{}' is documented more than once\n\
+                             note: header names are case-insensitive",
+                            header.name
+                        ),
+                        span,
+                    ));
+                }
+                self.doc_info.headers.push(header);
+            }
             None if !trimmed.is_empty() => {
                 // Not in a section - this is title or description
-                if title_set {
-                    in_description = true;
-                    description_lines.push(trimmed.to_string());
+                if self.title_set {
+                    self.in_description = true;
+                    self.description_lines.push(trimmed.to_string());
                 } else {
-                    doc_info.title = Some(trimmed.to_string());
-                    title_set = true;
+                    self.doc_info.title = Some(trimmed.to_string());
+                    self.title_set = true;
                 }
             }
-            None if trimmed.is_empty() && in_description => {
+            None if trimmed.is_empty() && self.in_description => {
                 // Empty line in description
-                description_lines.push(String::new());
+                self.description_lines.push(String::new());
             }
             _ => {
                 // Empty line or unrecognized content in a section - skip
             }
         }
+
+        Ok(())
     }
 
-    // Finalize any remaining pending content
-    if let Some((status, type_str, desc, sp)) = pending_response {
-        let response_info = annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
-        doc_info.responses.push(response_info);
+    /// Run the cross-line validations and return the parsed docs, or every
+    /// error found along the way
+    fn finish(mut self) -> Result<DocInfo, ParseError> {
+        let mut doc_info = self.doc_info;
+
+        if !self.description_lines.is_empty() {
+            doc_info.description = Some(self.description_lines.join("\n").trim().to_string());
+        }
+
+        // Validate that all example status codes are defined in responses
+        if !doc_info.examples.is_empty() && !doc_info.responses.is_empty() {
+            let response_codes: std::collections::HashSet<u16> =
+                doc_info.responses.iter().map(|r| r.status_code).collect();
+
+            for example in &doc_info.examples {
+                if !response_codes.contains(&example.status_code) {
+                    let available_codes: Vec<String> = doc_info
+                        .responses
+                        .iter()
+                        .map(|r| r.status_code.to_string())
+                        .collect();
+                    let available_list = available_codes.join(", ");
+
+                    self.errors.push(ParseError::with_span(
+                        format!(
+                            "Example status code {} is not defined in responses. Available status codes: {}",
+                            example.status_code,
+                            available_list
+                        ),
+                        example.span,
+                    ));
+                }
+            }
+        }
+
+        self.errors
+            .extend(validate_example_names(&doc_info.examples));
+
+        ParseError::combine(self.errors).map_or(Ok(doc_info), Err)
     }
-    if let Some((status, code, sp, _)) = pending_example {
-        let example_info = annotations::parse_example_from_parts(status, &code, sp)?;
-        doc_info.examples.push(example_info);
+}
+
+/// Ensure examples for one status code can all be emitted
+///
+/// A status code takes either a single unnamed example or any number of
+/// uniquely named ones, which end up in the `OpenAPI` `examples` map.
+fn validate_example_names(examples: &[ExampleInfo]) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+
+    for (i, example) in examples.iter().enumerate() {
+        let Some(previous) = examples[..i]
+            .iter()
+            .find(|e| e.status_code == example.status_code && e.name == example.name)
+            .or_else(|| {
+                examples[..i].iter().find(|e| {
+                    e.status_code == example.status_code
+                        && e.name.is_none() != example.name.is_none()
+                })
+            })
+        else {
+            continue;
+        };
+
+        let message = match (&previous.name, &example.name) {
+            (None, None) => format!(
+                "Multiple unnamed examples for status code {}\n\
+                 help: name each example, e.g. '{} \"empty list\": vec![]'\n\
+                 note: only one unnamed example is allowed per status code",
+                example.status_code, example.status_code
+            ),
+            (Some(_), Some(name)) => format!(
+                "Example '{}' is defined more than once for status code {}\n\
+                 help: give each example for a status code a unique name",
+                name, example.status_code
+            ),
+            _ => format!(
+                "Status code {} mixes named and unnamed examples\n\
+                 help: name every example for this status code, e.g. '{} \"default\": ...'",
+                example.status_code, example.status_code
+            ),
+        };
+        errors.push(ParseError::with_span(message, example.span));
     }
 
-    if !description_lines.is_empty() {
-        doc_info.description = Some(description_lines.join("\n").trim().to_string());
+    errors
+}
+
+/// Return the header definition of an indented `header Name: Type - description`
+/// line in the `# Responses` section.
+///
+/// The line must be indented past the single space that usually follows `///`,
+/// so a description continuation that happens to start with "header" is not
+/// mistaken for a header.
+fn response_header_line(text: &str) -> Option<&str> {
+    let text = text.strip_prefix(' ').unwrap_or(text);
+    if !text.starts_with(char::is_whitespace) {
+        return None;
     }
+    text.trim()
+        .strip_prefix("header ")
+        .map(str::trim)
+        .filter(|rest| !rest.is_empty())
+}
 
-    // Validate that all example status codes are defined in responses
-    if !doc_info.examples.is_empty() && !doc_info.responses.is_empty() {
-        let response_codes: std::collections::HashSet<u16> =
-            doc_info.responses.iter().map(|r| r.status_code).collect();
+/// Whether a line opens or closes a fenced code block
+fn is_code_fence(trimmed: &str) -> bool {
+    matches!(trimmed, "```" | "```rust" | "```rs")
+}
 
-        for example in &doc_info.examples {
-            if !response_codes.contains(&example.status_code) {
-                let available_codes: Vec<String> = doc_info
-                    .responses
-                    .iter()
-                    .map(|r| r.status_code.to_string())
-                    .collect();
-                let available_list = available_codes.join(", ");
-
-                return Err(ParseError::with_span(
-                    format!(
-                        "Example status code {} is not defined in responses. Available status codes: {}",
-                        example.status_code,
-                        available_list
-                    ),
-                    example.span,
-                ));
+/// Start an example from the text following its `<status>:` / `Example:` prefix
+///
+/// Returns the collected code and the bracket depth. The example is complete
+/// when the depth is 0 and the code is non-empty; otherwise the following lines
+/// are fed to [`continue_example`].
+fn start_example(code: &str) -> (String, usize) {
+    if is_code_fence(code) {
+        return (String::new(), CODE_BLOCK_MODE);
+    }
+
+    let mut depth: usize = 0;
+    for ch in code.chars() {
+        match ch {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    (code.to_string(), depth)
+}
+
+/// Add a line to a pending multi-line example
+///
+/// Returns `true` once the example is complete, either because the closing
+/// backticks of a code block were found or because all brackets are balanced.
+fn continue_example(code: &mut String, depth: &mut usize, trimmed: &str) -> bool {
+    if *depth == CODE_BLOCK_MODE {
+        // In code block mode - looking for closing backticks
+        if trimmed == "```" && !code.is_empty() {
+            return true;
+        }
+        if !(code.is_empty() && is_code_fence(trimmed)) {
+            if !code.is_empty() {
+                code.push('\n');
             }
+            code.push_str(trimmed);
         }
+        return false;
+    }
+
+    // Check if first line is switching to code block mode
+    if code.is_empty() && is_code_fence(trimmed) {
+        *depth = CODE_BLOCK_MODE;
+        return false;
     }
 
-    Ok(doc_info)
+    // Normal bracket/brace tracking mode
+    if !code.is_empty() {
+        code.push('\n');
+    }
+    code.push_str(trimmed);
+
+    for ch in trimmed.chars() {
+        match ch {
+            '{' | '[' | '(' => *depth += 1,
+            '}' | ']' | ')' => *depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    *depth == 0 && !code.trim().is_empty()
+}
+
+/// Store an unfinished request body example, reporting invalid code
+fn finalize_body_example(doc_info: &mut DocInfo, code: &str, span: Span) -> Result<(), ParseError> {
+    let example = annotations::parse_request_body_example(code, span)?;
+    if let Some(body) = doc_info.request_body.as_mut() {
+        body.example = Some(example);
+        body.example_span = span;
+    }
+    Ok(())
+}
+
+/// Strip a case-insensitive `Key:` prefix from a line
+fn strip_key<'a>(trimmed: &'a str, key: &str) -> Option<&'a str> {
+    trimmed
+        .get(..key.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(key))
+        .map(|_| &trimmed[key.len()..])
 }
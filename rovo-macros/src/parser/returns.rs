@@ -0,0 +1,257 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{GenericArgument, PathArguments, ReturnType, Type};
+
+use super::types::InferredResponse;
+
+/// Success status code constants that may be returned next to a body
+const SUCCESS_STATUS_CODES: &[(&str, u16)] = &[
+    ("OK", 200),
+    ("CREATED", 201),
+    ("ACCEPTED", 202),
+    ("NON_AUTHORITATIVE_INFORMATION", 203),
+    ("NO_CONTENT", 204),
+    ("RESET_CONTENT", 205),
+    ("PARTIAL_CONTENT", 206),
+];
+
+/// Infer the success response of a handler from its concrete return type
+///
+/// Only `Json<T>`, `StatusCode`, `()` and tuples ending in one of them are
+/// understood, optionally wrapped in `Result`; anything else, including
+/// `impl Trait`, returns `None`. A `StatusCode` in the return type is resolved
+/// by looking for a single success `StatusCode::*` constant in the function body.
+pub fn infer_response(input: &TokenStream) -> Option<InferredResponse> {
+    let func = syn::parse2::<syn::ImplItemFn>(input.clone()).ok()?;
+    let ReturnType::Type(_, return_type) = &func.sig.output else {
+        // No return type means `()`
+        return Some(InferredResponse {
+            status_code: 204,
+            response_type: quote::quote! { () },
+            body_type: None,
+        });
+    };
+
+    let body_status = body_success_status(&func.block.to_token_stream());
+    infer_from_type(return_type, body_status)
+}
+
+/// The error type `E` of a handler returning `Result<T, E>`
+///
+/// Used to merge the responses declared by `rovo::ErrorResponses` into the
+/// operation; types that do not implement it are ignored at compile time.
+pub fn error_type(input: &TokenStream) -> Option<TokenStream> {
+    let func = syn::parse2::<syn::ImplItemFn>(input.clone()).ok()?;
+    let ReturnType::Type(_, return_type) = &func.sig.output else {
+        return None;
+    };
+
+    let mut ty = return_type.as_ref();
+    while let Type::Paren(syn::TypeParen { elem, .. }) | Type::Group(syn::TypeGroup { elem, .. }) =
+        ty
+    {
+        ty = elem;
+    }
+
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .nth(1)
+        .map(ToTokens::to_token_stream)
+}
+
+fn infer_from_type(ty: &Type, body_status: Option<u16>) -> Option<InferredResponse> {
+    match ty {
+        Type::Paren(paren) => infer_from_type(&paren.elem, body_status),
+        Type::Group(group) => infer_from_type(&group.elem, body_status),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Some(InferredResponse {
+            status_code: 204,
+            response_type: ty.to_token_stream(),
+            body_type: None,
+        }),
+        Type::Tuple(tuple) => {
+            // `(StatusCode, ..., Body)` is documented by its last element
+            let has_status = tuple.elems.iter().any(is_status_code);
+            let body = tuple.elems.last()?;
+            let mut response = infer_from_type(body, None)?;
+            if has_status {
+                response.status_code = body_status.unwrap_or(response.status_code);
+            }
+            Some(response)
+        }
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            match segment.ident.to_string().as_str() {
+                "Result" => infer_from_type(first_type_argument(segment)?, body_status),
+                "StatusCode" => Some(InferredResponse {
+                    status_code: body_status.unwrap_or(200),
+                    response_type: quote::quote! { () },
+                    body_type: None,
+                }),
+                "Json" => Some(InferredResponse {
+                    status_code: 200,
+                    response_type: ty.to_token_stream(),
+                    body_type: first_type_argument(segment).map(ToTokens::to_token_stream),
+                }),
+                // Other types (`Redirect`, `Response`, ...) don't say which
+                // status they answer with
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_status_code(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path)
+        if path.path.segments.last().is_some_and(|s| s.ident == "StatusCode"))
+}
+
+fn first_type_argument(segment: &syn::PathSegment) -> Option<&Type> {
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// The success status code used in the body, if there is exactly one
+fn body_success_status(body: &TokenStream) -> Option<u16> {
+    let mut found = Vec::new();
+    collect_status_constants(body, &mut found);
+    found.sort_unstable();
+    found.dedup();
+    match found.as_slice() {
+        [status] => Some(*status),
+        _ => None,
+    }
+}
+
+fn collect_status_constants(tokens: &TokenStream, found: &mut Vec<u16>) {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => collect_status_constants(&group.stream(), found),
+            TokenTree::Ident(ident) if ident == "StatusCode" => {
+                // `StatusCode :: NAME`
+                if let (Some(TokenTree::Punct(a)), Some(TokenTree::Punct(b))) =
+                    (tokens.get(i + 1), tokens.get(i + 2))
+                {
+                    if a.as_char() == ':' && b.as_char() == ':' {
+                        if let Some(TokenTree::Ident(name)) = tokens.get(i + 3) {
+                            let name = name.to_string();
+                            if let Some((_, code)) =
+                                SUCCESS_STATUS_CODES.iter().find(|(n, _)| *n == name)
+                            {
+                                found.push(*code);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn infer(tokens: &TokenStream) -> Option<(u16, String, Option<String>)> {
+        infer_response(tokens).map(|r| {
+            (
+                r.status_code,
+                r.response_type.to_string(),
+                r.body_type.map(|t| t.to_string()),
+            )
+        })
+    }
+
+    #[test]
+    fn ignores_impl_trait() {
+        assert!(infer(&quote! { async fn a() -> impl IntoApiResponse { Json(()) } }).is_none());
+    }
+
+    #[test]
+    fn ignores_types_without_a_known_status() {
+        assert!(infer(&quote! { async fn a() -> Redirect { todo!() } }).is_none());
+        assert!(infer(&quote! { async fn a() -> Response { todo!() } }).is_none());
+        assert!(
+            infer(&quote! { async fn a() -> Result<Response, ApiError> { todo!() } }).is_none()
+        );
+    }
+
+    #[test]
+    fn infers_json() {
+        let (status, ty, body) = infer(&quote! { async fn a() -> Json<User> { todo!() } }).unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(ty, "Json < User >");
+        assert_eq!(body.as_deref(), Some("User"));
+    }
+
+    #[test]
+    fn infers_result_ok_type() {
+        let (status, ty, _) =
+            infer(&quote! { async fn a() -> Result<Json<User>, ApiError> { todo!() } }).unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(ty, "Json < User >");
+    }
+
+    #[test]
+    fn infers_status_from_body() {
+        let (status, ty, _) = infer(&quote! {
+            async fn a() -> (StatusCode, Json<User>) {
+                if x { return (StatusCode::NOT_FOUND, Json(u)); }
+                (StatusCode::CREATED, Json(u))
+            }
+        })
+        .unwrap();
+        assert_eq!(status, 201);
+        assert_eq!(ty, "Json < User >");
+    }
+
+    #[test]
+    fn ambiguous_status_falls_back_to_ok() {
+        let (status, ..) = infer(&quote! {
+            async fn a() -> StatusCode {
+                if x { StatusCode::OK } else { StatusCode::ACCEPTED }
+            }
+        })
+        .unwrap();
+        assert_eq!(status, 200);
+    }
+
+    #[test]
+    fn extracts_error_type() {
+        let error = |tokens: TokenStream| error_type(&tokens).map(|t| t.to_string());
+        assert_eq!(
+            error(quote! { async fn a() -> Result<Json<User>, ApiError> { todo!() } }).as_deref(),
+            Some("ApiError")
+        );
+        assert!(error(quote! { async fn a() -> Result<Json<User>> { todo!() } }).is_none());
+        assert!(error(quote! { async fn a() -> Json<User> { todo!() } }).is_none());
+    }
+
+    #[test]
+    fn infers_no_content_for_unit() {
+        assert_eq!(infer(&quote! { async fn a() {} }).unwrap().0, 204);
+        assert_eq!(infer(&quote! { async fn a() -> () {} }).unwrap().0, 204);
+    }
+}
@@ -1,6 +1,6 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 
-use super::types::PathParamInfo;
+use super::types::{PathParamInfo, QueryParamInfo, RequestBodyInfo};
 
 /// Extract path parameter information from function tokens
 /// Looks for patterns like:
@@ -9,25 +9,159 @@ use super::types::PathParamInfo;
 /// - `Path(MyStruct { id }): Path<MyStruct>` (struct destructuring)
 /// - Multiple Path extractors: `Path(id): Path<Uuid>, Path(name): Path<String>`
 pub fn extract_path_info(tokens: &TokenStream) -> Option<PathParamInfo> {
+    let info = extract_extractor_info(tokens, "Path")?;
+
+    Some(PathParamInfo {
+        bindings: info.bindings,
+        inner_type: info.inner_type,
+        is_struct_pattern: info.is_struct_pattern,
+    })
+}
+
+/// Extract query parameter information from function tokens
+/// Looks for patterns like:
+/// - `Query(params): Query<ListParams>` (opaque struct binding)
+/// - `Query(ListParams { page, limit }): Query<ListParams>` (struct destructuring)
+///
+/// Unlike path parameters, the field names of a destructured struct are kept
+/// as bindings, since they are the query parameter names.
+pub fn extract_query_info(tokens: &TokenStream) -> Option<QueryParamInfo> {
+    let info = extract_extractor_info(tokens, "Query")?;
+
+    Some(QueryParamInfo {
+        bindings: if info.is_struct_pattern {
+            info.struct_fields
+        } else {
+            info.bindings
+        },
+        inner_type: info.inner_type,
+        is_struct_pattern: info.is_struct_pattern,
+    })
+}
+
+/// Extract the request body type from the function arguments
+/// Looks for `Json<T>` or `Form<T>` in the argument list (not the return type or
+/// body), e.g. `Json(payload): Json<CreateTodo>` or `form: Form<Login>`.
+pub fn extract_request_body_info(tokens: &TokenStream) -> Option<RequestBodyInfo> {
+    let args = function_args(tokens)?.to_string();
+
+    ["Json", "Form"].iter().find_map(|extractor| {
+        let mut search_from = 0;
+        while let Some(pos) = args[search_from..].find(extractor) {
+            let abs_pos = search_from + pos;
+            search_from = abs_pos + extractor.len();
+
+            let is_word_start = args[..abs_pos]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+            let after = args[search_from..].trim_start();
+            if !is_word_start || !after.starts_with('<') {
+                continue;
+            }
+
+            // Find the matching closing angle bracket
+            let mut depth = 0;
+            for (byte_idx, ch) in after.char_indices() {
+                match ch {
+                    '<' => depth += 1,
+                    '>' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            let inner_type = after[1..byte_idx].trim().parse().ok()?;
+                            return Some(RequestBodyInfo { inner_type });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
+    })
+}
+
+/// Return the token stream of the function's argument list, skipping over
+/// any generic parameters (which may contain `Fn(..)` bounds)
+fn function_args(tokens: &TokenStream) -> Option<TokenStream> {
+    let mut after_fn = false;
+    let mut angle_depth = 0usize;
+    let mut prev_dash = false;
+    for token in tokens.clone() {
+        let is_dash = matches!(&token, TokenTree::Punct(p) if p.as_char() == '-');
+        match token {
+            TokenTree::Ident(ident) if ident == "fn" => after_fn = true,
+            TokenTree::Punct(p) if after_fn && p.as_char() == '<' => angle_depth += 1,
+            // `->` inside a bound like `F: Fn(u8) -> u8` does not close a generic
+            TokenTree::Punct(p) if after_fn && p.as_char() == '>' && !prev_dash => {
+                angle_depth = angle_depth.saturating_sub(1);
+            }
+            TokenTree::Group(group)
+                if after_fn && angle_depth == 0 && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                return Some(group.stream());
+            }
+            _ => {}
+        }
+        prev_dash = is_dash;
+    }
+    None
+}
+
+/// Find the span of the first `Extractor<...>` type in the function arguments,
+/// e.g. the `Query` of `Query(q): Query<String>`
+pub fn extractor_type_span(tokens: &TokenStream, extractor: &str) -> Option<Span> {
+    let args: Vec<TokenTree> = function_args(tokens)?.into_iter().collect();
+    args.windows(2).find_map(|pair| match pair {
+        [TokenTree::Ident(ident), TokenTree::Punct(punct)]
+            if *ident == extractor && punct.as_char() == '<' =>
+        {
+            Some(ident.span())
+        }
+        _ => None,
+    })
+}
+
+/// Find the span of a `HeaderMap` argument, whose headers can't be inferred
+pub fn header_map_span(tokens: &TokenStream) -> Option<Span> {
+    function_args(tokens)?
+        .into_iter()
+        .find_map(|token| match token {
+            TokenTree::Ident(ident) if ident == "HeaderMap" => Some(ident.span()),
+            _ => None,
+        })
+}
+
+/// Raw information about all `Extractor(...)` patterns in a function signature
+struct ExtractorInfo {
+    bindings: Vec<String>,
+    struct_fields: Vec<String>,
+    inner_type: String,
+    is_struct_pattern: bool,
+}
+
+/// Find every `Extractor(binding): Extractor<Type>` pattern for the given
+/// extractor name and collect bindings and inner types
+fn extract_extractor_info(tokens: &TokenStream, extractor: &str) -> Option<ExtractorInfo> {
     let token_str = tokens.to_string();
 
     let mut all_bindings = Vec::new();
+    let mut all_fields = Vec::new();
     let mut all_types = Vec::new();
     let mut any_struct_pattern = false;
     let mut search_start = 0;
 
-    // Find ALL Path() patterns in the token stream
-    while let Some(rel_pos) = find_path_pattern(&token_str[search_start..]) {
-        let path_pos = search_start + rel_pos;
+    // Find ALL Extractor() patterns in the token stream
+    while let Some(rel_pos) = find_extractor_pattern(&token_str[search_start..], extractor) {
+        let pattern_pos = search_start + rel_pos;
 
         // Determine skip length based on whether there's a space
-        let skip_len = if token_str[path_pos..].starts_with("Path(") {
-            5 // "Path("
+        let skip_len = if token_str[pattern_pos + extractor.len()..].starts_with('(') {
+            extractor.len() + 1 // "Path("
         } else {
-            6 // "Path ("
+            extractor.len() + 2 // "Path ("
         };
 
-        let after_open = &token_str[path_pos + skip_len..];
+        let after_open = &token_str[pattern_pos + skip_len..];
 
         // Find matching closing paren for the binding
         // Use char_indices() to get byte offsets for safe string slicing
@@ -48,43 +182,47 @@ pub fn extract_path_info(tokens: &TokenStream) -> Option<PathParamInfo> {
         }
 
         if close_pos == 0 && depth != 0 {
-            search_start = path_pos + skip_len;
+            search_start = pattern_pos + skip_len;
             continue;
         }
 
         let binding_content = after_open[..close_pos].trim();
 
         // Check if this is a struct destructuring pattern (contains '{')
-        if binding_content.contains('{') {
+        if let Some(brace_pos) = binding_content.find('{') {
             any_struct_pattern = true;
-        }
 
-        // Extract bindings from this Path extractor
-        if !binding_content.contains('{') {
-            if binding_content.starts_with('(') {
-                // Tuple pattern like "(a, b)"
-                let inner = binding_content
-                    .trim_start_matches('(')
-                    .trim_end_matches(')');
-                for s in inner.split(',') {
-                    let binding = s.trim().to_string();
-                    if !binding.is_empty() && !all_bindings.contains(&binding) {
-                        all_bindings.push(binding);
-                    }
+            // Collect the destructured field names, e.g. "Params { page, limit: l, .. }"
+            let fields = binding_content[brace_pos + 1..].trim_end_matches('}');
+            for field in fields.split(',') {
+                let name = field.split(':').next().unwrap_or_default().trim();
+                if !name.is_empty() && name != ".." && !all_fields.iter().any(|f| f == name) {
+                    all_fields.push(name.to_string());
                 }
-            } else if !binding_content.is_empty() {
-                // Single binding like "id"
-                let binding = binding_content.to_string();
-                if !all_bindings.contains(&binding) {
+            }
+        } else if binding_content.starts_with('(') {
+            // Tuple pattern like "(a, b)"
+            let inner = binding_content
+                .trim_start_matches('(')
+                .trim_end_matches(')');
+            for s in inner.split(',') {
+                let binding = s.trim().to_string();
+                if !binding.is_empty() && !all_bindings.contains(&binding) {
                     all_bindings.push(binding);
                 }
             }
+        } else if !binding_content.is_empty() {
+            // Single binding like "id"
+            let binding = binding_content.to_string();
+            if !all_bindings.contains(&binding) {
+                all_bindings.push(binding);
+            }
         }
 
-        // Extract the type from Path<Type>
+        // Extract the type from Extractor<Type>
         let rest = &after_open[close_pos..];
-        if let Some(type_start) = rest.find("Path") {
-            let after_type_path = &rest[type_start + 4..];
+        if let Some(type_start) = rest.find(extractor) {
+            let after_type_path = &rest[type_start + extractor.len()..];
             if let Some(angle_open) = after_type_path.find('<') {
                 let after_angle = &after_type_path[angle_open + 1..];
 
@@ -115,8 +253,8 @@ pub fn extract_path_info(tokens: &TokenStream) -> Option<PathParamInfo> {
             }
         }
 
-        // Move past this Path() for the next iteration
-        search_start = path_pos + skip_len + close_pos;
+        // Move past this Extractor() for the next iteration
+        search_start = pattern_pos + skip_len + close_pos;
     }
 
     if all_bindings.is_empty() && !any_struct_pattern {
@@ -130,27 +268,54 @@ pub fn extract_path_info(tokens: &TokenStream) -> Option<PathParamInfo> {
         std::cmp::Ordering::Less => String::new(),
     };
 
-    Some(PathParamInfo {
+    Some(ExtractorInfo {
         bindings: all_bindings,
+        struct_fields: all_fields,
         inner_type,
         is_struct_pattern: any_struct_pattern,
     })
 }
 
-/// Find the next "Path(" or "Path (" pattern in a string
-fn find_path_pattern(s: &str) -> Option<usize> {
+/// Find the next "Extractor(" or "Extractor (" pattern in a string
+fn find_extractor_pattern(s: &str, extractor: &str) -> Option<usize> {
     // Find "Path(" or "Path (" but not "Path<" (which is the type, not binding)
     let mut search_from = 0;
-    while let Some(pos) = s[search_from..].find("Path") {
+    while let Some(pos) = s[search_from..].find(extractor) {
         let abs_pos = search_from + pos;
-        let after = &s[abs_pos + 4..];
+        let after = &s[abs_pos + extractor.len()..];
+
+        // Skip identifiers that merely end with the extractor name (e.g. `RawQuery`)
+        let is_word_start = s[..abs_pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric() && c != '_');
 
-        if after.starts_with('(') || after.starts_with(" (") {
+        if is_word_start && (after.starts_with('(') || after.starts_with(" (")) {
             return Some(abs_pos);
         }
 
-        // Skip this "Path" and continue searching
-        search_from = abs_pos + 4;
+        // Skip this occurrence and continue searching
+        search_from = abs_pos + extractor.len();
+    }
+    None
+}
+
+/// Find the next `State<` type (as opposed to a `State(..)` binding or an
+/// identifier like `AppState`)
+fn find_state_type(s: &str) -> Option<usize> {
+    let mut search_from = 0;
+    while let Some(pos) = s[search_from..].find("State") {
+        let abs_pos = search_from + pos;
+        let after = s[abs_pos + "State".len()..].trim_start();
+        let is_word_start = s[..abs_pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+
+        if is_word_start && after.starts_with('<') {
+            return Some(abs_pos);
+        }
+        search_from = abs_pos + "State".len();
     }
     None
 }
@@ -158,11 +323,15 @@ fn find_path_pattern(s: &str) -> Option<usize> {
 /// Extract the state type from State<T> in function parameters
 /// Returns None if no State extractor is found (meaning state type is ())
 pub fn extract_state_type(tokens: &TokenStream) -> Option<TokenStream> {
-    let token_str = tokens.to_string();
+    // Only look at the argument list so generic bounds and doc comments that
+    // mention `State` are not mistaken for the extractor
+    let token_str = function_args(tokens)
+        .unwrap_or_else(|| tokens.clone())
+        .to_string();
 
     // Look for pattern "State < SomeType >"
     // This is a simplified parser that looks for State<...>
-    if let Some(state_pos) = token_str.find("State") {
+    if let Some(state_pos) = find_state_type(&token_str) {
         let after_state = &token_str[state_pos..];
         if let Some(open_bracket) = after_state.find('<') {
             // Find the matching closing bracket
@@ -284,7 +453,7 @@ mod tests {
         );
         let tokens: TokenStream = code.parse().unwrap();
 
-        let result = parse_rovo_function(tokens);
+        let result = parse_rovo_function(TokenStream::new(), tokens);
         assert!(result.is_ok(), "Should parse successfully");
 
         let (func_item, doc_info) = result.unwrap();
@@ -373,12 +542,12 @@ mod tests {
 
     #[test]
     fn handles_multiple_path_extractors_multiline() {
-        let tokens: TokenStream = r#"
+        let tokens: TokenStream = r"
             async fn get_todo(
                 Path(id): Path<Uuid>,
                 Path(id2): Path<String>,
             ) -> impl IntoApiResponse { }
-        "#
+        "
         .parse()
         .unwrap();
         let result = extract_path_info(&tokens);
@@ -398,6 +567,51 @@ mod tests {
         assert_eq!(info.bindings.len(), 2);
     }
 
+    // Query parameter extraction tests
+
+    #[test]
+    fn extracts_primitive_query_binding() {
+        let tokens: TokenStream = "Query(page): Query<u32>".parse().unwrap();
+        let info = extract_query_info(&tokens).unwrap();
+        assert_eq!(info.bindings, vec!["page"]);
+        assert_eq!(info.inner_type, "u32");
+        assert!(!info.is_struct_pattern);
+    }
+
+    #[test]
+    fn extracts_destructured_query_fields() {
+        let tokens: TokenStream = "Query(ListParams { page, limit: l, .. }): Query<ListParams>"
+            .parse()
+            .unwrap();
+        let info = extract_query_info(&tokens).unwrap();
+        assert_eq!(info.bindings, vec!["page", "limit"]);
+        assert_eq!(info.inner_type, "ListParams");
+        assert!(info.is_struct_pattern);
+    }
+
+    #[test]
+    fn extracts_opaque_query_binding() {
+        let tokens: TokenStream = "State(s): State<AppState>, Query(params): Query<ListParams>"
+            .parse()
+            .unwrap();
+        let info = extract_query_info(&tokens).unwrap();
+        assert_eq!(info.bindings, vec!["params"]);
+        assert_eq!(info.inner_type, "ListParams");
+        assert!(!info.is_struct_pattern);
+    }
+
+    #[test]
+    fn query_extraction_ignores_raw_query() {
+        let tokens: TokenStream = "RawQuery(raw): RawQuery".parse().unwrap();
+        assert!(extract_query_info(&tokens).is_none());
+    }
+
+    #[test]
+    fn query_extraction_ignores_path() {
+        let tokens: TokenStream = "Path(id): Path<u64>".parse().unwrap();
+        assert!(extract_query_info(&tokens).is_none());
+    }
+
     // State type extraction tests
 
     #[test]
@@ -574,20 +788,30 @@ mod tests {
 
     #[test]
     fn find_path_pattern_returns_none_for_path_type_only() {
-        // Test the find_path_pattern helper with just Path<T> (no binding)
+        // Test the find_extractor_pattern helper with just Path<T> (no binding)
         let s = "Path<u64>";
-        let result = find_path_pattern(s);
+        let result = find_extractor_pattern(s, "Path");
         assert!(result.is_none());
     }
 
     #[test]
     fn find_path_pattern_finds_path_with_space() {
         let s = "Path (id)";
-        let result = find_path_pattern(s);
+        let result = find_extractor_pattern(s, "Path");
         assert!(result.is_some());
         assert_eq!(result.unwrap(), 0);
     }
 
+    #[test]
+    fn extracts_generic_state_type_from_signature() {
+        let tokens: TokenStream =
+            "#[doc = \" Returns State<Other>\"] async fn list<R: Repo + HasState<u8>, F: Fn(u8) -> u8>(State(s): State<AppState<R>>) where R: Clone {}"
+                .parse()
+                .unwrap();
+        let result = extract_state_type(&tokens);
+        assert_eq!(result.unwrap().to_string(), "AppState < R >");
+    }
+
     #[test]
     fn extract_state_handles_malformed_input() {
         // No closing bracket
@@ -631,4 +855,39 @@ mod tests {
         // No type should result in empty inner_type
         assert!(info.inner_type.is_empty());
     }
+
+    // Request body extraction tests
+
+    #[test]
+    fn extracts_json_body_type() {
+        let tokens: TokenStream =
+            "async fn create(Json(todo): Json<CreateTodo>) -> Json<Todo> { Json(todo) }"
+                .parse()
+                .unwrap();
+        let info = extract_request_body_info(&tokens).unwrap();
+        assert_eq!(info.inner_type.to_string(), "CreateTodo");
+    }
+
+    #[test]
+    fn extracts_form_body_with_generic_type() {
+        let tokens: TokenStream = "async fn login(form: axum::Form<Vec<Login>>) {}"
+            .parse()
+            .unwrap();
+        let info = extract_request_body_info(&tokens).unwrap();
+        assert_eq!(info.inner_type.to_string(), "Vec < Login >");
+    }
+
+    #[test]
+    fn ignores_json_in_return_type() {
+        let tokens: TokenStream = "async fn list(Query(q): Query<String>) -> Json<Vec<Todo>> {}"
+            .parse()
+            .unwrap();
+        assert!(extract_request_body_info(&tokens).is_none());
+    }
+
+    #[test]
+    fn ignores_json_suffixed_identifiers() {
+        let tokens: TokenStream = "async fn raw(body: RawJson<Value>) {}".parse().unwrap();
+        assert!(extract_request_body_info(&tokens).is_none());
+    }
 }
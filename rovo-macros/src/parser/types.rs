@@ -6,11 +6,25 @@ pub struct ResponseInfo {
     pub status_code: u16,
     pub response_type: TokenStream,
     pub description: String,
+    /// Response headers from indented `header Name: Type - description` lines
+    pub headers: Vec<HeaderDoc>,
+}
+
+/// Success response inferred from a handler's return type
+#[derive(Debug, Clone)]
+pub struct InferredResponse {
+    pub status_code: u16,
+    /// Type documenting the response, e.g. `Json<User>` or `()`
+    pub response_type: TokenStream,
+    /// Body type whose doc comment describes the response, e.g. `User`
+    pub body_type: Option<TokenStream>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ExampleInfo {
     pub status_code: u16,
+    /// Name from `<status> "name": <expr>`, used as the `examples` map key
+    pub name: Option<String>,
     pub example_code: TokenStream,
     pub span: Span,
 }
@@ -26,6 +40,63 @@ pub struct PathParamDoc {
     pub span: Span,
 }
 
+/// Information about a query parameter from the `# Query Parameters` doc section
+#[derive(Debug, Clone)]
+pub struct QueryParamDoc {
+    /// Parameter name (e.g., "page", "limit")
+    pub name: String,
+    /// Parameter description
+    pub description: String,
+    /// Whether the parameter was marked `(required)`
+    pub required: bool,
+    /// Default value from a `(default: x)` marker
+    pub default: Option<String>,
+    /// Span for error reporting
+    pub span: Span,
+}
+
+/// Information about a request header from the `# Headers` doc section
+#[derive(Debug, Clone)]
+pub struct HeaderDoc {
+    /// Header name (e.g., "X-Request-Id")
+    pub name: String,
+    /// Rust type used for the header schema (e.g., `String`, `Uuid`)
+    pub header_type: TokenStream,
+    /// Header description
+    pub description: String,
+    /// Whether the header was marked `(required)`
+    pub required: bool,
+    /// Span for error reporting
+    pub span: Span,
+}
+
+/// Request body documentation from the `# Request Body` doc section
+#[derive(Debug, Clone)]
+pub struct RequestBodyDoc {
+    /// Body description, joined from the free-form lines of the section
+    pub description: Option<String>,
+    /// `Some(false)` for an `(optional)` marker, `Some(true)` for `(required)`
+    pub required: Option<bool>,
+    /// Media type from a `Content-Type: <type>` line
+    pub content_type: Option<String>,
+    /// Example expression from an `Example: <expr>` line
+    pub example: Option<TokenStream>,
+    /// Span of the example for error reporting
+    pub example_span: Span,
+}
+
+impl RequestBodyDoc {
+    pub const fn new(span: Span) -> Self {
+        Self {
+            description: None,
+            required: None,
+            content_type: None,
+            example: None,
+            example_span: span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DocInfo {
     pub title: Option<String>,
@@ -39,6 +110,101 @@ pub struct DocInfo {
     pub hidden: bool,
     /// Path parameter documentation from `# Path Parameters` section
     pub path_params: Vec<PathParamDoc>,
+    /// Query parameter documentation from `# Query Parameters` section
+    pub query_params: Vec<QueryParamDoc>,
+    /// Request header documentation from `# Headers` section
+    pub headers: Vec<HeaderDoc>,
+    /// Request body documentation from `# Request Body` section
+    pub request_body: Option<RequestBodyDoc>,
+    /// How the operation ID is derived when no `@id` is given
+    pub id_style: IdStyle,
+    /// `rovo.toml` the ID style was read from, tracked so changes trigger a rebuild
+    pub config_file: Option<String>,
+    /// Soft problems to report as compiler warnings
+    pub warnings: Vec<DocWarning>,
+}
+
+/// Kind of soft documentation problem, named for `#[rovo(allow(...))]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// The handler documents no responses
+    MissingResponses,
+    /// A `# Section` header rovo does not know
+    UnknownSections,
+    /// A path binding without a `# Path Parameters` entry
+    UndocumentedPathParams,
+    /// A `HeaderMap` argument without a `# Headers` section
+    UndocumentedHeaders,
+}
+
+impl WarningKind {
+    pub const ALL: [Self; 4] = [
+        Self::MissingResponses,
+        Self::UnknownSections,
+        Self::UndocumentedPathParams,
+        Self::UndocumentedHeaders,
+    ];
+
+    /// Name used in `#[rovo(allow(...))]`
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::MissingResponses => "missing_responses",
+            Self::UnknownSections => "unknown_sections",
+            Self::UndocumentedPathParams => "undocumented_path_params",
+            Self::UndocumentedHeaders => "undocumented_headers",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// How the default operation ID is derived from the handler, chosen with
+/// `#[rovo(id_style = "...")]` or `id_style` in `rovo.toml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdStyle {
+    /// The function name, `get_user`
+    #[default]
+    Name,
+    /// The module path without the crate name, then the function name, `api_users_get_user`
+    Module,
+    /// The function name in camelCase, `getUser`
+    Camel,
+    /// The first tag, then the function name, `users_get_user`
+    Tag,
+}
+
+impl IdStyle {
+    pub const ALL: [Self; 4] = [Self::Name, Self::Module, Self::Camel, Self::Tag];
+
+    /// Name used in `id_style = "..."`
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Module => "module",
+            Self::Camel => "camel",
+            Self::Tag => "tag",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|style| style.name() == name)
+    }
+
+    /// Every style name, for error notes
+    pub fn names() -> String {
+        let names: Vec<&str> = Self::ALL.into_iter().map(Self::name).collect();
+        names.join(", ")
+    }
+}
+
+/// A soft documentation problem, reported as a compiler warning
+#[derive(Debug, Clone)]
+pub struct DocWarning {
+    pub kind: WarningKind,
+    pub message: String,
+    pub span: Span,
 }
 
 /// Information about path parameters extracted from function signature
@@ -52,6 +218,25 @@ pub struct PathParamInfo {
     pub is_struct_pattern: bool,
 }
 
+/// Information about query parameters extracted from function signature
+#[derive(Debug, Clone)]
+pub struct QueryParamInfo {
+    /// Binding names from the Query pattern, or the field names when the
+    /// struct is destructured (e.g., `["page", "limit"]`)
+    pub bindings: Vec<String>,
+    /// The inner type as a string (e.g., "u32" or "`ListParams`")
+    pub inner_type: String,
+    /// Whether this is a struct destructuring pattern
+    pub is_struct_pattern: bool,
+}
+
+/// Information about the request body extractor in the function signature
+#[derive(Debug, Clone)]
+pub struct RequestBodyInfo {
+    /// The body type, e.g. `CreateTodo` for `Json<CreateTodo>`
+    pub inner_type: TokenStream,
+}
+
 #[derive(Clone)]
 pub struct FuncItem {
     pub name: Ident,
@@ -59,6 +244,17 @@ pub struct FuncItem {
     pub state_type: Option<TokenStream>,
     /// Path parameter info extracted from function signature
     pub path_params: Option<PathParamInfo>,
+    /// Query parameter info extracted from function signature
+    pub query_params: Option<QueryParamInfo>,
+    /// Request body extractor (`Json<T>` / `Form<T>`) from function signature
+    pub request_body: Option<RequestBodyInfo>,
+    /// Generic parameters and where clause of the function
+    pub generics: syn::Generics,
+    /// Success response inferred from the return type, used without `# Responses`
+    pub inferred_response: Option<InferredResponse>,
+    /// Error type `E` of a `Result<T, E>` return type, whose declared error
+    /// responses are merged into the operation
+    pub error_type: Option<TokenStream>,
 }
 
 impl FuncItem {
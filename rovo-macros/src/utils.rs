@@ -1,3 +1,35 @@
+/// Known primitive types that map to `OpenAPI` types
+const PRIMITIVE_TYPES: &[&str] = &[
+    "String", "u64", "u32", "u16", "u8", "i64", "i32", "i16", "i8", "bool", "Uuid",
+];
+
+/// Check if a type is a known primitive
+pub fn is_primitive_type(type_name: &str) -> bool {
+    PRIMITIVE_TYPES.contains(&type_name.trim())
+}
+
+/// Check if a tuple contains only primitives
+pub fn is_primitive_tuple(type_str: &str) -> bool {
+    let inner = type_str
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+    inner.split(',').map(str::trim).all(is_primitive_type)
+}
+
+/// Extract individual types from a tuple type string like "(Uuid, u32)"
+pub fn extract_tuple_types(type_str: &str) -> Vec<String> {
+    let inner = type_str
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+    inner
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 /// Calculate Levenshtein distance between two strings
 pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     let len1 = s1.len();
@@ -23,6 +55,34 @@ pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     matrix[len1][len2]
 }
 
+/// Find the closest matching doc comment section
+pub fn find_closest_section(input: &str) -> Option<&'static str> {
+    const SECTIONS: &[&str] = &[
+        "Path Parameters",
+        "Query Parameters",
+        "Headers",
+        "Request Body",
+        "Responses",
+        "Examples",
+        "Metadata",
+    ];
+
+    let input_lower = input.to_lowercase();
+    let mut best_match = None;
+    let mut best_distance = usize::MAX;
+
+    for &section in SECTIONS {
+        let distance = levenshtein_distance(&input_lower, &section.to_lowercase());
+        // Only suggest if distance is small (≤ 3 characters different)
+        if distance < best_distance && distance <= 3 {
+            best_distance = distance;
+            best_match = Some(section);
+        }
+    }
+
+    best_match
+}
+
 /// Find the closest matching annotation
 pub fn find_closest_annotation(input: &str) -> Option<&'static str> {
     const ANNOTATIONS: &[&str] = &["tag", "security", "id", "hidden", "rovo-ignore"];
@@ -42,3 +102,34 @@ pub fn find_closest_annotation(input: &str) -> Option<&'static str> {
 
     best_match
 }
+
+/// Convert a `snake_case` name to `camelCase`
+pub fn to_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize = false;
+    for c in name.trim_start_matches('_').chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            result.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Replace everything but ASCII letters, digits and underscores with `_`, the
+/// same rule `rovo` uses for operation ID prefixes
+pub fn to_identifier(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
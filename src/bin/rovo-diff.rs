@@ -0,0 +1,54 @@
+//! Compare two `OpenAPI` specs and report breaking changes.
+//!
+//! ```text
+//! rovo-diff <old-spec> <new-spec>
+//! ```
+//!
+//! Specs ending in `.yaml` or `.yml` are read as YAML, anything else as JSON.
+//! Exits with 1 when a change may break existing clients and 2 on errors.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use rovo::aide::openapi::OpenApi;
+
+const USAGE: &str = "usage: rovo-diff <old-spec> <new-spec>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}\n\nExits with 1 when a change may break existing clients.");
+        return ExitCode::SUCCESS;
+    }
+    let [old, new] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    let specs = load(Path::new(old)).and_then(|old| Ok((old, load(Path::new(new))?)));
+    let (old, new) = match specs {
+        Ok(specs) => specs,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let diff = rovo::diff::compare(&old, &new);
+    print!("{diff}");
+    if diff.is_breaking() {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn load(path: &Path) -> Result<OpenApi, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml" | "yml") => serde_yaml::from_str(&contents).map_err(|err| err.to_string()),
+        _ => serde_json::from_str(&contents).map_err(|err| err.to_string()),
+    };
+    parsed.map_err(|err| format!("failed to parse {}: {err}", path.display()))
+}
@@ -0,0 +1,925 @@
+//! Detecting breaking changes between two `OpenAPI` specs.
+//!
+//! [`compare`] walks the operations of an old and a new spec and classifies
+//! every difference as breaking or not from a client's point of view: removed
+//! operations, new required parameters, narrowed enums, removed response
+//! fields and changed types break clients, while additions usually don't.
+//!
+//! Run against the spec of the previous release, it keeps accidental breaking
+//! changes out of the public API:
+//!
+//! ```no_run
+//! # use rovo::Router;
+//! # use rovo::aide::openapi::OpenApi;
+//! # fn api_router() -> Router<()> { Router::new().with_oas(OpenApi::default()) }
+//! #[test]
+//! fn no_breaking_changes() {
+//!     let released: OpenApi =
+//!         serde_json::from_str(include_str!("../openapi.released.json")).unwrap();
+//!     let diff = rovo::diff::compare(&released, &api_router().to_openapi().unwrap());
+//!     assert!(!diff.is_breaking(), "{diff}");
+//! }
+//! ```
+//!
+//! The `rovo-diff` binary does the same for two spec files, for use in CI.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::LazyLock;
+
+use aide::openapi::OpenApi;
+use serde_json::{Map, Value};
+
+use crate::error::OperationRef;
+
+/// Keywords setting a lower bound, tightened when they grow
+const LOWER_BOUNDS: &[&str] = &[
+    "minimum",
+    "exclusiveMinimum",
+    "minLength",
+    "minItems",
+    "minProperties",
+];
+
+/// Keywords setting an upper bound, tightened when they shrink
+const UPPER_BOUNDS: &[&str] = &[
+    "maximum",
+    "exclusiveMaximum",
+    "maxLength",
+    "maxItems",
+    "maxProperties",
+];
+
+static EMPTY_OBJECT: LazyLock<Map<String, Value>> = LazyLock::new(Map::new);
+
+/// Most `$ref`s followed in a row before giving up on a reference chain
+const MAX_REF_DEPTH: usize = 32;
+
+/// A single difference between two specs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// The operation the change affects
+    pub operation: OperationRef,
+    /// Where in the operation, e.g. ``response 200 `items[]` ``, or empty for
+    /// the operation itself
+    pub location: String,
+    /// Whether existing clients may break
+    pub breaking: bool,
+    /// What changed, e.g. ``property `email` removed``
+    pub description: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.location.is_empty() {
+            write!(f, "{}: {}", self.operation, self.description)
+        } else {
+            write!(
+                f,
+                "{}: {}: {}",
+                self.operation, self.location, self.description
+            )
+        }
+    }
+}
+
+/// Every difference between two specs, returned by [`compare`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecDiff {
+    /// The changes, in operation order
+    pub changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    /// Whether the specs are equivalent
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether any change may break existing clients
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// The changes that may break existing clients
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    /// The changes existing clients can ignore
+    pub fn non_breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| !change.breaking)
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        for (title, breaking) in [("Breaking changes", true), ("Non-breaking changes", false)] {
+            let mut changes = self
+                .changes
+                .iter()
+                .filter(|change| change.breaking == breaking)
+                .peekable();
+            if changes.peek().is_none() {
+                continue;
+            }
+            writeln!(f, "{title}:")?;
+            for change in changes {
+                writeln!(f, "  - {change}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compare an old spec with a new one
+///
+/// Request parameters and bodies break clients when they accept less than
+/// before, responses when they may return something clients haven't seen.
+/// Schemas behind `$ref`s are compared by content, so renaming a component
+/// is not a change.
+#[must_use]
+pub fn compare(old: &OpenApi, new: &OpenApi) -> SpecDiff {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+
+    let mut differ = Differ {
+        old: &old,
+        new: &new,
+        changes: Vec::new(),
+    };
+    differ.operations();
+    SpecDiff {
+        changes: differ.changes,
+    }
+}
+
+/// Every operation of a serialized spec with its method and path, in spec order
+pub(crate) fn operations(spec: &Value) -> Vec<(OperationRef, &Value)> {
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+
+    paths
+        .iter()
+        .flat_map(|(path, item)| {
+            item.as_object()
+                .into_iter()
+                .flatten()
+                .filter(|(method, _)| is_method(method))
+                .map(move |(method, operation)| {
+                    let operation_ref = OperationRef {
+                        method: method.to_uppercase(),
+                        path: path.clone(),
+                    };
+                    (operation_ref, operation)
+                })
+        })
+        .collect()
+}
+
+/// Whether a path item key holds an operation
+pub(crate) fn is_method(key: &str) -> bool {
+    matches!(
+        key,
+        "get" | "put" | "post" | "delete" | "options" | "head" | "patch" | "trace"
+    )
+}
+
+/// Which way data flows through a schema, deciding what breaks clients
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Sent by clients: accepting less is breaking
+    Request,
+    /// Sent to clients: returning more is breaking
+    Response,
+}
+
+impl Direction {
+    /// Whether a change that makes the schema stricter (or looser) breaks clients
+    fn breaks(self, tightened: bool) -> bool {
+        tightened == (self == Self::Request)
+    }
+}
+
+/// Where a schema being compared sits
+struct Site<'a> {
+    operation: &'a OperationRef,
+    location: &'a str,
+    direction: Direction,
+}
+
+impl Site<'_> {
+    fn location(&self, field: &str) -> String {
+        if field.is_empty() {
+            self.location.to_string()
+        } else {
+            format!("{} `{field}`", self.location)
+        }
+    }
+}
+
+struct Differ<'a> {
+    old: &'a Value,
+    new: &'a Value,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn push(
+        &mut self,
+        operation: &OperationRef,
+        location: String,
+        breaking: bool,
+        description: String,
+    ) {
+        self.changes.push(Change {
+            operation: operation.clone(),
+            location,
+            breaking,
+            description,
+        });
+    }
+
+    fn operations(&mut self) {
+        let old_operations = operations(self.old);
+        let new_operations = operations(self.new);
+
+        for (operation, old) in &old_operations {
+            match new_operations.iter().find(|(new, _)| new == operation) {
+                Some((_, new)) => self.operation(operation, old, new),
+                None => self.push(operation, String::new(), true, "operation removed".into()),
+            }
+        }
+        for (operation, _) in &new_operations {
+            if !old_operations.iter().any(|(old, _)| old == operation) {
+                self.push(operation, String::new(), false, "operation added".into());
+            }
+        }
+    }
+
+    fn operation(&mut self, operation: &OperationRef, old: &'a Value, new: &'a Value) {
+        let deprecated = |value: &Value| value.get("deprecated") == Some(&Value::Bool(true));
+        if !deprecated(old) && deprecated(new) {
+            self.push(operation, String::new(), false, "deprecated".into());
+        }
+
+        self.parameters(operation, old, new);
+        self.request_body(operation, old, new);
+        self.responses(operation, old, new);
+    }
+
+    fn parameters(&mut self, operation: &OperationRef, old: &'a Value, new: &'a Value) {
+        let old_parameters = parameters(self.old, operation, old);
+        let new_parameters = parameters(self.new, operation, new);
+
+        for ((name, place), old) in &old_parameters {
+            let location = format!("{place} parameter `{name}`");
+            let Some((_, new)) = new_parameters
+                .iter()
+                .find(|(key, _)| *key == (*name, *place))
+            else {
+                self.push(operation, location, false, "removed".into());
+                continue;
+            };
+
+            match (is_required(old), is_required(new)) {
+                (false, true) => {
+                    self.push(operation, location.clone(), true, "became required".into());
+                }
+                (true, false) => {
+                    self.push(operation, location.clone(), false, "became optional".into());
+                }
+                _ => {}
+            }
+            if let (Some(old), Some(new)) = (old.get("schema"), new.get("schema")) {
+                let site = Site {
+                    operation,
+                    location: &location,
+                    direction: Direction::Request,
+                };
+                self.schema(&site, "", old, new, &mut Vec::new());
+            }
+        }
+        for ((name, place), new) in &new_parameters {
+            if old_parameters
+                .iter()
+                .any(|(key, _)| *key == (*name, *place))
+            {
+                continue;
+            }
+            let location = format!("{place} parameter `{name}`");
+            if is_required(new) {
+                self.push(operation, location, true, "added as required".into());
+            } else {
+                self.push(operation, location, false, "added".into());
+            }
+        }
+    }
+
+    fn request_body(&mut self, operation: &OperationRef, old: &'a Value, new: &'a Value) {
+        let old_body = old.get("requestBody").map(|body| resolve(self.old, body));
+        let new_body = new.get("requestBody").map(|body| resolve(self.new, body));
+        let location = "request body";
+
+        match (old_body, new_body) {
+            (None, None) => {}
+            (Some(_), None) => self.push(operation, location.into(), false, "removed".into()),
+            (None, Some(new)) if is_required(new) => {
+                self.push(operation, location.into(), true, "added as required".into());
+            }
+            (None, Some(_)) => self.push(operation, location.into(), false, "added".into()),
+            (Some(old), Some(new)) => {
+                match (is_required(old), is_required(new)) {
+                    (false, true) => {
+                        self.push(operation, location.into(), true, "became required".into());
+                    }
+                    (true, false) => {
+                        self.push(operation, location.into(), false, "became optional".into());
+                    }
+                    _ => {}
+                }
+                self.content(operation, location, Direction::Request, old, new);
+            }
+        }
+    }
+
+    fn responses(&mut self, operation: &OperationRef, old: &'a Value, new: &'a Value) {
+        let old_responses = object(old, "responses");
+        let new_responses = object(new, "responses");
+
+        for (status, old) in old_responses {
+            let location = format!("response {status}");
+            if let Some(new) = new_responses.get(status) {
+                let (old, new) = (resolve(self.old, old), resolve(self.new, new));
+                self.content(operation, &location, Direction::Response, old, new);
+            } else {
+                // Clients may rely on a success response, error responses are best-effort
+                let breaking = status.starts_with('2');
+                self.push(operation, location, breaking, "removed".into());
+            }
+        }
+        for status in new_responses.keys() {
+            if !old_responses.contains_key(status) {
+                self.push(
+                    operation,
+                    format!("response {status}"),
+                    false,
+                    "added".into(),
+                );
+            }
+        }
+    }
+
+    /// Compare the media types of a request body or response
+    fn content(
+        &mut self,
+        operation: &OperationRef,
+        location: &str,
+        direction: Direction,
+        old: &'a Value,
+        new: &'a Value,
+    ) {
+        let old_content = object(old, "content");
+        let new_content = object(new, "content");
+
+        for (media_type, old) in old_content {
+            let Some(new) = new_content.get(media_type) else {
+                self.push(
+                    operation,
+                    location.to_string(),
+                    true,
+                    format!("media type `{media_type}` removed"),
+                );
+                continue;
+            };
+            if let (Some(old), Some(new)) = (old.get("schema"), new.get("schema")) {
+                // Name the media type only when there is more than one
+                let location = if old_content.len() > 1 {
+                    format!("{location} ({media_type})")
+                } else {
+                    location.to_string()
+                };
+                let site = Site {
+                    operation,
+                    location: &location,
+                    direction,
+                };
+                self.schema(&site, "", old, new, &mut Vec::new());
+            }
+        }
+        for media_type in new_content.keys() {
+            if !old_content.contains_key(media_type) {
+                self.push(
+                    operation,
+                    location.to_string(),
+                    false,
+                    format!("media type `{media_type}` added"),
+                );
+            }
+        }
+    }
+
+    /// Compare two schemas, `seen` holding the pairs of `$ref`s being compared
+    /// further up to stop at recursive types
+    fn schema(
+        &mut self,
+        site: &Site<'_>,
+        field: &str,
+        old: &'a Value,
+        new: &'a Value,
+        seen: &mut Vec<(&'a str, &'a str)>,
+    ) {
+        if let (Some(old_ref), Some(new_ref)) = (reference(old), reference(new)) {
+            if seen.contains(&(old_ref, new_ref)) {
+                return;
+            }
+            seen.push((old_ref, new_ref));
+            self.schema(
+                site,
+                field,
+                resolve(self.old, old),
+                resolve(self.new, new),
+                seen,
+            );
+            seen.pop();
+            return;
+        }
+
+        let (old, new) = (resolve(self.old, old), resolve(self.new, new));
+        let (old_inner, old_nullable) = unwrap_nullable(old);
+        let (new_inner, new_nullable) = unwrap_nullable(new);
+        let location = site.location(field);
+        self.nullability(site, &location, old_nullable, new_nullable);
+        if !std::ptr::eq(old, old_inner) || !std::ptr::eq(new, new_inner) {
+            self.schema(site, field, old_inner, new_inner, seen);
+            return;
+        }
+
+        let old_object = old.as_object().unwrap_or(&EMPTY_OBJECT);
+        let new_object = new.as_object().unwrap_or(&EMPTY_OBJECT);
+
+        // Constraints of a different type would only repeat the type change
+        if !self.types(site, &location, old_object, new_object) {
+            self.constraints(site, &location, old_object, new_object);
+        }
+        self.enums(site, &location, old_object, new_object);
+        self.properties(site, field, old_object, new_object, seen);
+
+        if let (Some(old_items), Some(new_items)) =
+            (old_object.get("items"), new_object.get("items"))
+        {
+            self.schema(site, &join(field, "[]"), old_items, new_items, seen);
+        }
+        if let (Some(old_values @ Value::Object(_)), Some(new_values @ Value::Object(_))) = (
+            old_object.get("additionalProperties"),
+            new_object.get("additionalProperties"),
+        ) {
+            self.schema(site, &join(field, "{}"), old_values, new_values, seen);
+        }
+
+        self.variants(site, field, &location, old_object, new_object, seen);
+    }
+
+    fn nullability(&mut self, site: &Site<'_>, location: &str, old: bool, new: bool) {
+        match (old, new) {
+            (false, true) => {
+                let description = match site.direction {
+                    Direction::Request => "now accepts null",
+                    Direction::Response => "may now be null",
+                };
+                self.push(
+                    site.operation,
+                    location.to_string(),
+                    site.direction.breaks(false),
+                    description.into(),
+                );
+            }
+            (true, false) => {
+                let description = match site.direction {
+                    Direction::Request => "no longer accepts null",
+                    Direction::Response => "is no longer null",
+                };
+                self.push(
+                    site.operation,
+                    location.to_string(),
+                    site.direction.breaks(true),
+                    description.into(),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Compare `allOf`, `anyOf` and `oneOf` variants by position
+    fn variants(
+        &mut self,
+        site: &Site<'_>,
+        field: &str,
+        location: &str,
+        old: &'a Map<String, Value>,
+        new: &'a Map<String, Value>,
+        seen: &mut Vec<(&'a str, &'a str)>,
+    ) {
+        for keyword in ["allOf", "anyOf", "oneOf"] {
+            let variants = |object: &'a Map<String, Value>| {
+                object
+                    .get(keyword)
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+            };
+            let (old_variants, new_variants) = (
+                variants(old).unwrap_or_default(),
+                variants(new).unwrap_or_default(),
+            );
+            if old_variants.len() == new_variants.len() {
+                for (index, (old, new)) in old_variants.iter().zip(new_variants).enumerate() {
+                    self.schema(
+                        site,
+                        &join(field, &format!("{keyword}[{index}]")),
+                        old,
+                        new,
+                        seen,
+                    );
+                }
+                continue;
+            }
+
+            // Variants can't be matched up, only their number compared
+            let fewer = new_variants.len() < old_variants.len();
+            let breaking = if keyword == "allOf" {
+                true
+            } else {
+                site.direction.breaks(fewer)
+            };
+            let description = format!(
+                "`{keyword}` variants changed from {} to {}",
+                old_variants.len(),
+                new_variants.len()
+            );
+            self.push(site.operation, location.to_string(), breaking, description);
+        }
+    }
+
+    /// Compare the types of two schemas, returning whether they changed
+    fn types(
+        &mut self,
+        site: &Site<'_>,
+        location: &str,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+    ) -> bool {
+        let (old_types, new_types) = (types(old), types(new));
+        let description = match (&old_types, &new_types) {
+            (Some(old_types), Some(new_types)) if old_types != new_types => {
+                let widened = accepts_all(new_types, old_types);
+                let narrowed = accepts_all(old_types, new_types);
+                let breaking = match site.direction {
+                    Direction::Request => !widened,
+                    Direction::Response => !narrowed,
+                };
+                let description = format!(
+                    "type changed from {} to {}",
+                    list(old_types),
+                    list(new_types)
+                );
+                self.push(site.operation, location.to_string(), breaking, description);
+                return true;
+            }
+            (None, Some(new_types)) => (true, format!("restricted to type {}", list(new_types))),
+            (Some(old_types), None) => (
+                false,
+                format!("no longer restricted to type {}", list(old_types)),
+            ),
+            _ => return false,
+        };
+
+        let (tightened, description) = description;
+        self.push(
+            site.operation,
+            location.to_string(),
+            site.direction.breaks(tightened),
+            description,
+        );
+        false
+    }
+
+    fn enums(
+        &mut self,
+        site: &Site<'_>,
+        location: &str,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+    ) {
+        let (tightened, description) = match (enum_values(old), enum_values(new)) {
+            (Some(old_values), Some(new_values)) => {
+                let removed: Vec<&Value> = old_values
+                    .iter()
+                    .filter(|value| !new_values.contains(value))
+                    .copied()
+                    .collect();
+                let added: Vec<&Value> = new_values
+                    .iter()
+                    .filter(|value| !old_values.contains(value))
+                    .copied()
+                    .collect();
+                if !removed.is_empty() {
+                    self.push(
+                        site.operation,
+                        location.to_string(),
+                        site.direction.breaks(true),
+                        format!("enum values removed: {}", values(&removed)),
+                    );
+                }
+                if added.is_empty() {
+                    return;
+                }
+                (false, format!("enum values added: {}", values(&added)))
+            }
+            (None, Some(new_values)) => (
+                true,
+                format!("restricted to enum values {}", values(&new_values)),
+            ),
+            (Some(_), None) => (false, "no longer restricted to enum values".to_string()),
+            (None, None) => return,
+        };
+
+        self.push(
+            site.operation,
+            location.to_string(),
+            site.direction.breaks(tightened),
+            description,
+        );
+    }
+
+    fn constraints(
+        &mut self,
+        site: &Site<'_>,
+        location: &str,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+    ) {
+        for (keywords, lower) in [(LOWER_BOUNDS, true), (UPPER_BOUNDS, false)] {
+            for keyword in keywords {
+                let bound =
+                    |object: &Map<String, Value>| object.get(*keyword).and_then(Value::as_f64);
+                let (old_bound, new_bound) = (bound(old), bound(new));
+                let tightened = match (old_bound, new_bound) {
+                    (Some(old_bound), Some(new_bound)) => match new_bound.partial_cmp(&old_bound) {
+                        Some(Ordering::Greater) => lower,
+                        Some(Ordering::Less) => !lower,
+                        _ => continue,
+                    },
+                    (None, Some(_)) => true,
+                    (Some(_), None) => false,
+                    _ => continue,
+                };
+                let description = describe_change(keyword, old.get(*keyword), new.get(*keyword));
+                self.push(
+                    site.operation,
+                    location.to_string(),
+                    site.direction.breaks(tightened),
+                    description,
+                );
+            }
+        }
+
+        // A different format or pattern accepts different values, so it breaks
+        // clients either way; adding one only restricts
+        for keyword in ["format", "pattern"] {
+            let (old_value, new_value) = (old.get(keyword), new.get(keyword));
+            let breaking = match (old_value, new_value) {
+                (Some(old_value), Some(new_value)) if old_value != new_value => true,
+                (None, Some(_)) => site.direction.breaks(true),
+                (Some(_), None) => site.direction.breaks(false),
+                _ => continue,
+            };
+            let description = describe_change(keyword, old_value, new_value);
+            self.push(site.operation, location.to_string(), breaking, description);
+        }
+    }
+
+    fn properties(
+        &mut self,
+        site: &Site<'_>,
+        field: &str,
+        old: &'a Map<String, Value>,
+        new: &'a Map<String, Value>,
+        seen: &mut Vec<(&'a str, &'a str)>,
+    ) {
+        let old_properties = old
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&EMPTY_OBJECT);
+        let new_properties = new
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&EMPTY_OBJECT);
+        let (old_required, new_required) = (required(old), required(new));
+        let location = site.location(field);
+
+        for (name, old_property) in old_properties {
+            let Some(new_property) = new_properties.get(name) else {
+                // Servers ignore fields they don't know, clients may need them
+                self.push(
+                    site.operation,
+                    location.clone(),
+                    site.direction == Direction::Response,
+                    format!("property `{name}` removed"),
+                );
+                continue;
+            };
+
+            match (
+                old_required.contains(name.as_str()),
+                new_required.contains(name.as_str()),
+            ) {
+                (false, true) => self.push(
+                    site.operation,
+                    location.clone(),
+                    site.direction.breaks(true),
+                    format!("property `{name}` became required"),
+                ),
+                (true, false) => self.push(
+                    site.operation,
+                    location.clone(),
+                    site.direction.breaks(false),
+                    format!("property `{name}` became optional"),
+                ),
+                _ => {}
+            }
+            self.schema(site, &join(field, name), old_property, new_property, seen);
+        }
+        for name in new_properties.keys() {
+            if old_properties.contains_key(name) {
+                continue;
+            }
+            let (breaking, description) = if new_required.contains(name.as_str()) {
+                (
+                    site.direction == Direction::Request,
+                    format!("required property `{name}` added"),
+                )
+            } else {
+                (false, format!("property `{name}` added"))
+            };
+            self.push(site.operation, location.clone(), breaking, description);
+        }
+    }
+}
+
+/// The parameters of an operation and its path item, keyed by name and
+/// location; the operation's own win
+fn parameters<'a>(
+    spec: &'a Value,
+    operation: &OperationRef,
+    value: &'a Value,
+) -> Vec<((&'a str, &'a str), &'a Value)> {
+    let path_item = spec
+        .get("paths")
+        .and_then(|paths| paths.get(&operation.path));
+    let mut parameters: Vec<((&str, &str), &Value)> = Vec::new();
+    for source in [path_item, Some(value)].into_iter().flatten() {
+        let Some(list) = source.get("parameters").and_then(Value::as_array) else {
+            continue;
+        };
+        for parameter in list {
+            let parameter = resolve(spec, parameter);
+            let (Some(name), Some(place)) = (
+                parameter.get("name").and_then(Value::as_str),
+                parameter.get("in").and_then(Value::as_str),
+            ) else {
+                continue;
+            };
+            parameters.retain(|(key, _)| *key != (name, place));
+            parameters.push(((name, place), parameter));
+        }
+    }
+    parameters
+}
+
+/// The object under `key`, or an empty one
+fn object<'v>(value: &'v Value, key: &str) -> &'v Map<String, Value> {
+    value
+        .get(key)
+        .and_then(Value::as_object)
+        .unwrap_or(&EMPTY_OBJECT)
+}
+
+fn is_required(value: &Value) -> bool {
+    value.get("required") == Some(&Value::Bool(true))
+}
+
+fn reference(value: &Value) -> Option<&str> {
+    value.get("$ref").and_then(Value::as_str)
+}
+
+/// Follow local `$ref`s until reaching something that isn't one
+fn resolve<'a>(spec: &'a Value, mut value: &'a Value) -> &'a Value {
+    for _ in 0..MAX_REF_DEPTH {
+        let Some(target) = reference(value)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| spec.pointer(pointer))
+        else {
+            break;
+        };
+        value = target;
+    }
+    value
+}
+
+/// Unwrap `anyOf: [T, { type: null }]`, as generated for `Option<T>`, and
+/// report whether the schema allows null in any of the ways it can say so
+fn unwrap_nullable(schema: &Value) -> (&Value, bool) {
+    for keyword in ["anyOf", "oneOf"] {
+        let Some(variants) = schema.get(keyword).and_then(Value::as_array) else {
+            continue;
+        };
+        if let [first, second] = variants.as_slice() {
+            let is_null =
+                |variant: &Value| variant.get("type").and_then(Value::as_str) == Some("null");
+            match (is_null(first), is_null(second)) {
+                (false, true) => return (first, true),
+                (true, false) => return (second, true),
+                _ => {}
+            }
+        }
+    }
+
+    let nullable = schema.get("nullable") == Some(&Value::Bool(true))
+        || schema
+            .get("type")
+            .and_then(Value::as_array)
+            .is_some_and(|types| types.iter().any(|ty| ty == "null"));
+    (schema, nullable)
+}
+
+/// The non-null types a schema allows, `None` if it doesn't say
+fn types(schema: &Map<String, Value>) -> Option<BTreeSet<&str>> {
+    let types: BTreeSet<&str> = match schema.get("type")? {
+        Value::String(ty) => std::iter::once(ty.as_str()).collect(),
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => return None,
+    };
+    let types: BTreeSet<&str> = types.into_iter().filter(|ty| *ty != "null").collect();
+    (!types.is_empty()).then_some(types)
+}
+
+/// Whether every type in `narrow` is accepted by `wide`, integers being numbers
+fn accepts_all(wide: &BTreeSet<&str>, narrow: &BTreeSet<&str>) -> bool {
+    narrow
+        .iter()
+        .all(|ty| wide.contains(ty) || (*ty == "integer" && wide.contains("number")))
+}
+
+/// The allowed values of an `enum` or `const`, without `null`
+fn enum_values(schema: &Map<String, Value>) -> Option<Vec<&Value>> {
+    if let Some(value) = schema.get("const") {
+        return Some(vec![value]);
+    }
+    let values = schema.get("enum")?.as_array()?;
+    Some(values.iter().filter(|value| !value.is_null()).collect())
+}
+
+fn required(schema: &Map<String, Value>) -> BTreeSet<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn describe_change(keyword: &str, old: Option<&Value>, new: Option<&Value>) -> String {
+    match (old, new) {
+        (Some(old), Some(new)) => format!("`{keyword}` changed from {old} to {new}"),
+        (None, Some(new)) => format!("`{keyword}` {new} added"),
+        (Some(old), None) => format!("`{keyword}` {old} removed"),
+        (None, None) => format!("`{keyword}` changed"),
+    }
+}
+
+fn join(field: &str, segment: &str) -> String {
+    match (field.is_empty(), segment) {
+        (true, _) => segment.to_string(),
+        (false, "[]") => format!("{field}[]"),
+        (false, _) => format!("{field}.{segment}"),
+    }
+}
+
+fn list(types: &BTreeSet<&str>) -> String {
+    types.iter().copied().collect::<Vec<_>>().join(" | ")
+}
+
+fn values(values: &[&Value]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,223 @@
+//! Downgrading a serialized `OpenAPI` 3.1 document to 3.0 for older tooling.
+
+use serde_json::{json, Map, Value};
+
+/// Version written into downgraded documents
+const OPENAPI_3_0: &str = "3.0.3";
+
+/// JSON Schema keywords without a 3.0 counterpart, dropped from schemas
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$anchor",
+    "$comment",
+    "$dynamicRef",
+    "$dynamicAnchor",
+    "unevaluatedProperties",
+    "unevaluatedItems",
+    "dependentRequired",
+    "dependentSchemas",
+    "if",
+    "then",
+    "else",
+    "contentEncoding",
+    "contentMediaType",
+    "contentSchema",
+    "propertyNames",
+    "patternProperties",
+    "contains",
+    "minContains",
+    "maxContains",
+];
+
+/// Convert a 3.1 document into its closest 3.0 equivalent
+///
+/// Schema features 3.0 can express are rewritten (`type: [T, "null"]` becomes
+/// `nullable: true`, `const` a single-value `enum`, tuples an `items` union),
+/// the rest is dropped.
+pub fn to_openapi_3_0(mut document: Value) -> Value {
+    let Some(root) = document.as_object_mut() else {
+        return document;
+    };
+
+    root.insert("openapi".to_string(), OPENAPI_3_0.into());
+    root.remove("webhooks");
+    root.remove("jsonSchemaDialect");
+
+    if let Some(info) = root.get_mut("info").and_then(Value::as_object_mut) {
+        info.remove("summary");
+        if let Some(license) = info.get_mut("license").and_then(Value::as_object_mut) {
+            license.remove("identifier");
+        }
+    }
+
+    if let Some(components) = root.get_mut("components").and_then(Value::as_object_mut) {
+        components.remove("pathItems");
+        if let Some(schemas) = components.get_mut("schemas").and_then(Value::as_object_mut) {
+            schemas.values_mut().for_each(downgrade_schema);
+        }
+    }
+
+    visit_schema_holders(&mut document);
+    document
+}
+
+/// Downgrade the schemas of parameters, headers and media types, which sit
+/// under a `schema` key anywhere outside `components.schemas`
+fn visit_schema_holders(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                match key.as_str() {
+                    "schema" => downgrade_schema(child),
+                    // Component schemas are handled separately, examples are user data
+                    "schemas" | "example" | "examples" => {}
+                    key if key.starts_with("x-") => {}
+                    _ => visit_schema_holders(child),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(visit_schema_holders),
+        _ => {}
+    }
+}
+
+fn downgrade_schema(schema: &mut Value) {
+    match schema {
+        // 3.0 has no boolean schemas
+        Value::Bool(true) => *schema = json!({}),
+        Value::Bool(false) => *schema = json!({ "not": {} }),
+        Value::Object(object) => downgrade_schema_object(object),
+        _ => {}
+    }
+}
+
+fn downgrade_schema_object(schema: &mut Map<String, Value>) {
+    // `anyOf: [T, { type: null }]`, as generated for `Option<T>`
+    for key in ["anyOf", "oneOf"] {
+        let Some(Value::Array(variants)) = schema.get_mut(key) else {
+            continue;
+        };
+        let before = variants.len();
+        variants.retain(|variant| !is_null_schema(variant));
+        if variants.len() < before {
+            schema.insert("nullable".to_string(), true.into());
+        }
+    }
+
+    // Tuples become arrays of the union of their item types
+    if let Some(Value::Array(mut items)) = schema.remove("prefixItems") {
+        if let Some(rest) = schema
+            .remove("items")
+            .filter(|rest| *rest != Value::Bool(false))
+        {
+            items.push(rest);
+        }
+        items.dedup();
+        let items = if items.len() == 1 {
+            items.remove(0)
+        } else {
+            json!({ "anyOf": items })
+        };
+        schema.insert("items".to_string(), items);
+    }
+
+    for key in ["items", "not"] {
+        if let Some(subschema) = schema.get_mut(key) {
+            downgrade_schema(subschema);
+        }
+    }
+    // `additionalProperties` may stay a boolean in 3.0
+    if let Some(additional @ Value::Object(_)) = schema.get_mut("additionalProperties") {
+        downgrade_schema(additional);
+    }
+    for key in ["properties", "$defs"] {
+        if let Some(Value::Object(properties)) = schema.get_mut(key) {
+            properties.values_mut().for_each(downgrade_schema);
+        }
+    }
+    for key in ["allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(variants)) = schema.get_mut(key) {
+            variants.iter_mut().for_each(downgrade_schema);
+        }
+    }
+
+    downgrade_type(schema);
+
+    if let Some(value) = schema.remove("const") {
+        schema.insert("enum".to_string(), json!([value]));
+    }
+
+    if let Some(Value::Array(mut examples)) = schema.remove("examples") {
+        if !examples.is_empty() && !schema.contains_key("example") {
+            schema.insert("example".to_string(), examples.swap_remove(0));
+        }
+    }
+
+    for (exclusive, inclusive) in [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(bound @ Value::Number(_)) = schema.remove(exclusive) {
+            schema.insert(inclusive.to_string(), bound);
+            schema.insert(exclusive.to_string(), true.into());
+        }
+    }
+
+    if schema.get("contentEncoding").and_then(Value::as_str) == Some("base64")
+        && !schema.contains_key("format")
+    {
+        schema.insert("format".to_string(), "byte".into());
+    }
+    for keyword in UNSUPPORTED_KEYWORDS {
+        schema.remove(*keyword);
+    }
+
+    // Siblings of `$ref` are ignored in 3.0, so the reference moves into `allOf`
+    if schema.len() > 1 {
+        if let Some(reference) = schema.remove("$ref") {
+            let mut all_of = match schema.remove("allOf") {
+                Some(Value::Array(all_of)) => all_of,
+                _ => Vec::new(),
+            };
+            all_of.insert(0, json!({ "$ref": reference }));
+            schema.insert("allOf".to_string(), Value::Array(all_of));
+        }
+    }
+}
+
+/// `type: [T, "null"]` becomes `type: T, nullable: true`
+fn downgrade_type(schema: &mut Map<String, Value>) {
+    let types: Vec<Value> = match schema.remove("type") {
+        Some(Value::Array(types)) => types,
+        Some(Value::String(ty)) if ty == "null" => vec![Value::String(ty)],
+        Some(ty) => {
+            schema.insert("type".to_string(), ty);
+            return;
+        }
+        None => return,
+    };
+
+    let (nulls, mut types): (Vec<Value>, Vec<Value>) = types
+        .into_iter()
+        .partition(|ty| ty.as_str() == Some("null"));
+    if !nulls.is_empty() {
+        schema.insert("nullable".to_string(), true.into());
+    }
+
+    match types.len() {
+        0 => {}
+        1 => {
+            schema.insert("type".to_string(), types.remove(0));
+        }
+        _ if !schema.contains_key("anyOf") => {
+            let variants: Vec<Value> = types.into_iter().map(|ty| json!({ "type": ty })).collect();
+            schema.insert("anyOf".to_string(), Value::Array(variants));
+        }
+        _ => {}
+    }
+}
+
+fn is_null_schema(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
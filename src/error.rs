@@ -0,0 +1,150 @@
+//! Errors reported when finishing a router's `OpenAPI` spec.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// An operation in the finished spec, identified by method and path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationRef {
+    /// Upper-case HTTP method, e.g. `GET`
+    pub method: String,
+    /// Path template, e.g. `/users/{id}`
+    pub path: String,
+}
+
+impl fmt::Display for OperationRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+/// An operation ID shared by more than one operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateOperationId {
+    /// The shared operation ID
+    pub id: String,
+    /// Every operation using it, in spec order
+    pub operations: Vec<OperationRef>,
+}
+
+/// A security scheme required by an operation but never defined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndefinedSecurityScheme {
+    /// Name of the missing scheme
+    pub scheme: String,
+    /// The operation requiring it, or `None` for the spec's global requirements
+    pub operation: Option<OperationRef>,
+}
+
+/// Error returned by [`Router::try_finish`](crate::Router::try_finish) and
+/// [`StatefulRouter::try_finish`](crate::StatefulRouter::try_finish).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The spec could not be serialized
+    Serialize {
+        /// The format being written, `JSON` or `YAML`
+        format: &'static str,
+        /// The serializer's error message
+        message: String,
+    },
+    /// Several operations share an operation ID
+    DuplicateOperationIds(Vec<DuplicateOperationId>),
+    /// Operations require security schemes that are not defined
+    UndefinedSecuritySchemes(Vec<UndefinedSecurityScheme>),
+    /// `$ref`s that don't point at anything in the spec
+    UnresolvedReferences(Vec<String>),
+    /// A schema in the spec could not be compiled for request or response validation
+    InvalidSchema {
+        /// The operation and part of it the schema belongs to
+        location: String,
+        /// The schema compiler's error message
+        message: String,
+    },
+    /// The spec could not be written to a file
+    Write {
+        /// The file being written
+        path: PathBuf,
+        /// The underlying I/O error
+        source: std::io::Error,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize { format, message } => {
+                write!(f, "failed to serialize OpenAPI spec to {format}: {message}")
+            }
+            Self::DuplicateOperationIds(duplicates) => {
+                write!(f, "OpenAPI spec has duplicate operation IDs:")?;
+                for duplicate in duplicates {
+                    let operations: Vec<String> = duplicate
+                        .operations
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect();
+                    write!(
+                        f,
+                        "\n  - `{}` is used by {}",
+                        duplicate.id,
+                        operations.join(", ")
+                    )?;
+                }
+                write!(
+                    f,
+                    "\nhelp: give the handlers distinct IDs with `@id` or `#[rovo(id = \"...\")]`, \
+                     or prefix them with `Router::disambiguate_operation_ids`"
+                )
+            }
+            Self::UndefinedSecuritySchemes(undefined) => {
+                write!(
+                    f,
+                    "OpenAPI spec uses security schemes that are not defined:"
+                )?;
+                for missing in undefined {
+                    match &missing.operation {
+                        Some(operation) => {
+                            write!(f, "\n  - {operation} requires `{}`", missing.scheme)?;
+                        }
+                        None => write!(
+                            f,
+                            "\n  - the global security requirement requires `{}`",
+                            missing.scheme
+                        )?,
+                    }
+                }
+                write!(
+                    f,
+                    "\nhelp: register them with `Router::security_scheme(name, SecurityScheme::...)`"
+                )
+            }
+            Self::UnresolvedReferences(references) => {
+                write!(f, "OpenAPI spec has unresolved references:")?;
+                for reference in references {
+                    write!(f, "\n  - `{reference}`")?;
+                }
+                Ok(())
+            }
+            Self::InvalidSchema { location, message } => {
+                write!(f, "invalid schema for {location}: {message}")
+            }
+            Self::Write { path, source } => {
+                write!(
+                    f,
+                    "failed to write OpenAPI spec to {}: {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -71,6 +71,50 @@
 //! /// 404: () - User not found
 //! ```
 //!
+//! Response headers go on indented `header` lines below their response:
+//! ```text
+//! /// 201: Json<User> - User created
+//! ///   header Location: String - URL of the new user
+//! ```
+//!
+//! Without a `# Responses` section, the success response is inferred from a
+//! concrete return type: `Json<T>` becomes a 200 described by `T`'s doc comment,
+//! `(StatusCode::CREATED, Json<T>)` a 201 and `()` a 204.
+//!
+//! Error responses shared by many handlers can be declared once by implementing
+//! [`ErrorResponses`] for the error type; handlers returning `Result<T, E>`
+//! inherit them.
+//!
+//! ### Query Parameters Section
+//! Document query parameters with optional `(required)` / `(default: x)` markers:
+//! ```text
+//! /// # Query Parameters
+//! ///
+//! /// page: Page number to return (default: 1)
+//! /// q: Search term (required)
+//! ```
+//! The names are the fields of the `Query<T>` struct, which derives `Deserialize`
+//! and `JsonSchema`; primitives like `Query<String>` are rejected at compile time.
+//!
+//! ### Headers Section
+//! Document request headers with their type and optional `(required)` marker:
+//! ```text
+//! /// # Headers
+//! ///
+//! /// X-Tenant-Id: Uuid (required) - Tenant the request acts on
+//! /// X-Request-Id: String - Correlation ID echoed in logs
+//! ```
+//!
+//! ### Request Body Section
+//! Describe the `Json<T>` / `Form<T>` body, with a type-checked example:
+//! ```text
+//! /// # Request Body
+//! ///
+//! /// The user to create (optional)
+//! /// Content-Type: application/json
+//! /// Example: User { id: 1, name: "Alice".into() }
+//! ```
+//!
 //! ### Examples Section
 //! Provide response examples with valid Rust expressions:
 //! ```text
@@ -80,6 +124,12 @@
 //! /// 404: ()
 //! ```
 //!
+//! Name examples to give several for one status code:
+//! ```text
+//! /// 200 "empty list": vec![]
+//! /// 200 "one user": vec![User { id: 1, name: "Alice".into() }]
+//! ```
+//!
 //! ### Metadata Section
 //! Add API metadata with annotations:
 //! ```text
@@ -93,12 +143,35 @@
 //!
 //! **Available metadata annotations:**
 //! - `@tag <name>` - Group endpoints by tags
-//! - `@security <scheme>` - Specify security requirements
-//! - `@id <operation_id>` - Set custom operation ID
+//! - `@security <scheme>` - Specify security requirements; the scheme must be defined with
+//!   [`Router::security_scheme`]
+//! - `@id <operation_id>` - Set custom operation ID; IDs must be unique across the
+//!   router, see [`Router::disambiguate_operation_ids`]. Without it the ID is
+//!   the function name, or follows `#[rovo(id_style = "name" | "module" | "camel" | "tag")]`
+//!   or `id_style` in a `rovo.toml` next to the crate's `Cargo.toml`
 //! - `@hidden` - Hide endpoint from documentation
 //!
+//! The same metadata can be given as attribute arguments, which are merged with
+//! the `# Metadata` section:
+//! ```text
+//! #[rovo(tag = "users", security = "bearer_auth", id = "getUserById", hidden)]
+//! ```
+//!
 //! **Special directives:**
 //! - `@rovo-ignore` - Stop processing annotations after this point
+//!
+//! Handlers without responses, unknown `# Section` headers, undocumented
+//! path bindings and `HeaderMap` arguments without `# Headers` produce compiler
+//! warnings, which can be silenced with `#[rovo(allow(missing_responses,
+//! unknown_sections, undocumented_path_params, undocumented_headers))]`.
+//!
+//! ## Handlers in `impl` Blocks
+//!
+//! Put `#[rovo]` on an `impl` block to use its `#[rovo]` associated functions
+//! as handlers, e.g. `get(Users::list)`. See [`DocumentedHandler`].
+//!
+//! Generic handlers (`async fn list<R: Repo>(...)`) are supported too and are
+//! routed with a turbofish: `get(list::<PgRepo>)`.
 
 pub use rovo_macros::rovo;
 
@@ -109,6 +182,207 @@ pub use aide;
 #[doc(hidden)]
 pub use ::schemars as __schemars;
 
+/// Raw `serde_json` crate re-export, used internally by the `#[rovo]` macro.
+#[doc(hidden)]
+pub use ::serde_json as __serde_json;
+
+/// Operation extension holding the handler's module path, set by the `#[rovo]`
+/// macro and removed again when the spec is finished.
+#[doc(hidden)]
+pub const __MODULE_PATH_EXTENSION: &str = "x-rovo-module-path";
+
+/// Operation ID of a handler using `id_style = "module"`, e.g. `api_users_get_user`
+/// for `get_user` in `my_app::api::users`.
+#[doc(hidden)]
+#[must_use]
+pub fn __module_operation_id(module_path: &str, name: &str) -> String {
+    format!("{}_{name}", spec::module_prefix(module_path))
+}
+
+/// Split a type's doc comment into a schema title and description, used
+/// internally by `#[derive(JsonSchema)]`. Each line is trimmed, dropping the
+/// space after `///`. A leading `# Heading` line becomes the title; otherwise
+/// the whole comment is the description.
+#[doc(hidden)]
+#[must_use]
+pub fn __title_and_description(doc: &str) -> (String, String) {
+    let doc = doc.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    let doc = doc.trim();
+    doc.strip_prefix('#').map_or_else(
+        || (String::new(), doc.to_string()),
+        |heading| {
+            let (title, description) = heading.split_once('\n').unwrap_or((heading, ""));
+            (
+                title.trim_start_matches('#').trim().to_string(),
+                description.trim().to_string(),
+            )
+        },
+    )
+}
+
+/// Description of a response inferred from the handler's return type, taken
+/// from the doc comment of its body type, used internally by the `#[rovo]` macro.
+#[doc(hidden)]
+pub fn __type_description<T: ::schemars::JsonSchema + ?Sized>(fallback: &str) -> String {
+    ::schemars::SchemaGenerator::default()
+        .into_root_schema_for::<T>()
+        .get("description")
+        .and_then(::serde_json::Value::as_str)
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+/// Add a named example to every media type of a response, used internally by
+/// the `#[rovo]` macro for `<status> "name": <expr>` entries in `# Examples`.
+#[doc(hidden)]
+pub fn __named_example<'t, T: ::serde::Serialize>(
+    mut res: aide::transform::TransformResponse<'t, T>,
+    name: &str,
+    example: impl Into<T>,
+) -> aide::transform::TransformResponse<'t, T> {
+    let value = ::serde_json::to_value(example.into()).ok();
+
+    for media in res.inner().content.values_mut() {
+        media.examples.insert(
+            name.to_string(),
+            aide::openapi::ReferenceOr::Item(aide::openapi::Example {
+                summary: Some(name.to_string()),
+                value: value.clone(),
+                ..Default::default()
+            }),
+        );
+    }
+
+    res
+}
+
+/// Error responses shared by every handler that returns an error type.
+///
+/// Implement this for your API error type and `#[rovo]` adds its responses to
+/// every handler returning `Result<T, YourError>`, so they don't need to be
+/// repeated in each `# Responses` section. Status codes documented explicitly
+/// in `# Responses` take precedence over the inherited ones.
+///
+/// # Example
+/// ```no_run
+/// use rovo::{rovo, ErrorResponses};
+/// use rovo::response::{IntoResponse, Json, Response};
+/// use rovo::schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema)]
+/// struct ApiError {
+///     message: String,
+/// }
+///
+/// impl IntoResponse for ApiError {
+///     fn into_response(self) -> Response {
+///         Json(self).into_response()
+///     }
+/// }
+///
+/// // Required by aide for any error type returned from a handler
+/// impl rovo::aide::OperationOutput for ApiError {
+///     type Inner = Self;
+/// }
+///
+/// impl ErrorResponses for ApiError {
+///     type Response = Json<ApiError>;
+///
+///     const RESPONSES: &'static [(u16, &'static str)] = &[
+///         (400, "Invalid request"),
+///         (401, "Missing or invalid credentials"),
+///         (500, "Internal server error"),
+///     ];
+/// }
+///
+/// /// Get the current user.
+/// ///
+/// /// # Responses
+/// ///
+/// /// 200: Json<String> - The user's name
+/// /// 401: Json<ApiError> - Not logged in
+/// #[rovo]
+/// async fn me() -> Result<Json<String>, ApiError> {
+///     Ok(Json("alice".to_string()))
+/// }
+/// ```
+pub trait ErrorResponses {
+    /// Response documented for every status code, usually `Json<Self>`
+    type Response: aide::OperationOutput;
+
+    /// Status codes this error produces, with their descriptions
+    const RESPONSES: &'static [(u16, &'static str)];
+}
+
+/// Selects between [`ErrorResponses`] and a no-op for a handler's error type
+/// by autoref specialization, used internally by the `#[rovo]` macro.
+#[doc(hidden)]
+pub struct __ErrorResponsesProbe<E: ?Sized>(std::marker::PhantomData<E>);
+
+impl<E: ?Sized> __ErrorResponsesProbe<E> {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait __ErrorResponsesViaTrait {
+    fn __error_responses<'t>(
+        &self,
+        op: aide::transform::TransformOperation<'t>,
+    ) -> aide::transform::TransformOperation<'t>;
+}
+
+impl<E: ErrorResponses + ?Sized> __ErrorResponsesViaTrait for __ErrorResponsesProbe<E> {
+    fn __error_responses<'t>(
+        &self,
+        mut op: aide::transform::TransformOperation<'t>,
+    ) -> aide::transform::TransformOperation<'t> {
+        let operation = op.inner_mut();
+
+        aide::generate::in_context(|ctx| {
+            for &(code, description) in E::RESPONSES {
+                let status = aide::openapi::StatusCode::Code(code);
+                let exists = operation
+                    .responses
+                    .as_ref()
+                    .is_some_and(|responses| responses.responses.contains_key(&status));
+                if exists {
+                    continue;
+                }
+
+                if let Some(mut response) =
+                    <E::Response as aide::OperationOutput>::operation_response(ctx, operation)
+                {
+                    response.description = description.to_string();
+                    operation
+                        .responses
+                        .get_or_insert_with(Default::default)
+                        .responses
+                        .insert(status, aide::openapi::ReferenceOr::Item(response));
+                }
+            }
+        });
+
+        op
+    }
+}
+
+#[doc(hidden)]
+pub trait __ErrorResponsesFallback {
+    fn __error_responses<'t>(
+        &self,
+        op: aide::transform::TransformOperation<'t>,
+    ) -> aide::transform::TransformOperation<'t> {
+        op
+    }
+}
+
+impl<E: ?Sized> __ErrorResponsesFallback for &__ErrorResponsesProbe<E> {}
+
 /// Re-export of the [`schemars`](::schemars) crate with rovo's `JsonSchema` derive.
 ///
 /// The `JsonSchema` derive exported here automatically resolves rovo's crate path,
@@ -162,6 +436,34 @@ pub use ::axum::response;
 /// ```
 pub use ::axum::http;
 
+/// Re-export of axum-extra's typed header extractor and the `headers` crate.
+///
+/// Requires the `headers` feature. `TypedHeader<T>` extractors in `#[rovo]` handlers
+/// are documented automatically as header parameters.
+///
+/// # Example
+/// ```no_run
+/// use rovo::{headers::UserAgent, TypedHeader};
+/// ```
+#[cfg(feature = "headers")]
+pub use ::axum_extra::{headers, TypedHeader};
+
+pub mod diff;
+mod downgrade;
+mod error;
+mod security;
+mod serve;
+mod spec;
+pub mod testing;
+#[cfg(feature = "validation")]
+mod validation;
+
+pub use error::{DuplicateOperationId, Error, OperationRef, UndefinedSecurityScheme};
+pub use security::SecurityScheme;
+pub use spec::{Format, OperationIdPrefix};
+#[cfg(feature = "validation")]
+pub use validation::{RequestValidation, ResponseValidation, Violation, ViolationLocation};
+
 use ::axum::Extension;
 use aide::axum::ApiRouter as AideApiRouter;
 use aide::openapi::OpenApi;
@@ -212,10 +514,12 @@ where
     S: Clone + Send + Sync + 'static,
 {
     fn nest_into(self, mut parent: Self, path: &str) -> Self {
-        parent.inner = parent.inner.nest(path, self.inner);
-        if parent.oas_spec.is_none() && self.oas_spec.is_some() {
-            parent.oas_spec = self.oas_spec;
-            parent.oas_route = self.oas_route;
+        let child = self.apply_defaults();
+        parent.inner = parent.inner.nest(path, child.inner);
+        parent.spec_options.merge(child.spec_options);
+        if parent.oas_spec.is_none() && child.oas_spec.is_some() {
+            parent.oas_spec = child.oas_spec;
+            parent.oas_route = child.oas_route;
         }
         parent
     }
@@ -227,6 +531,7 @@ where
 {
     fn nest_into(self, mut parent: Router<S>, path: &str) -> Router<S> {
         parent.inner = parent.inner.nest_api_service(path, self.inner);
+        parent.spec_options.merge(self.spec_options);
         if parent.oas_spec.is_none() && self.oas_spec.is_some() {
             parent.oas_spec = self.oas_spec;
             parent.oas_route = self.oas_route;
@@ -274,7 +579,80 @@ where
 pub struct Router<S = ()> {
     inner: AideApiRouter<S>,
     oas_spec: Option<OpenApi>,
-    oas_route: String,
+    oas_route: SpecRoute,
+    defaults: OperationDefaults,
+    spec_options: SpecOptions,
+}
+
+/// Response added by [`Router::default_response`]
+struct DefaultResponse {
+    status: u16,
+    description: String,
+    response: fn(
+        &mut aide::generate::GenContext,
+        &mut aide::openapi::Operation,
+    ) -> Option<aide::openapi::Response>,
+}
+
+/// Documentation applied to every operation of a [`Router`] that doesn't set it itself
+#[derive(Default)]
+struct OperationDefaults {
+    security: Vec<String>,
+    responses: Vec<DefaultResponse>,
+    tags: Vec<String>,
+}
+
+impl OperationDefaults {
+    fn apply<S>(&self, router: AideApiRouter<S>) -> AideApiRouter<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        if self.security.is_empty() && self.responses.is_empty() && self.tags.is_empty() {
+            return router;
+        }
+
+        router.with_path_items(|mut item| {
+            for (_, operation) in aide::util::iter_operations_mut(item.inner_mut()) {
+                self.apply_to(operation);
+            }
+            item
+        })
+    }
+
+    fn apply_to(&self, operation: &mut aide::openapi::Operation) {
+        if operation.security.is_empty() {
+            let mut op = aide::transform::TransformOperation::new(operation);
+            for scheme in &self.security {
+                op = op.security_requirement(scheme);
+            }
+        }
+
+        if operation.tags.is_empty() {
+            operation.tags.clone_from(&self.tags);
+        }
+
+        aide::generate::in_context(|ctx| {
+            for default in &self.responses {
+                let status = aide::openapi::StatusCode::Code(default.status);
+                let exists = operation
+                    .responses
+                    .as_ref()
+                    .is_some_and(|responses| responses.responses.contains_key(&status));
+                if exists {
+                    continue;
+                }
+
+                if let Some(mut response) = (default.response)(ctx, operation) {
+                    response.description.clone_from(&default.description);
+                    operation
+                        .responses
+                        .get_or_insert_with(Default::default)
+                        .responses
+                        .insert(status, aide::openapi::ReferenceOr::Item(response));
+                }
+            }
+        });
+    }
 }
 
 impl<S> Router<S>
@@ -287,7 +665,9 @@ where
         Self {
             inner: AideApiRouter::new(),
             oas_spec: None,
-            oas_route: "/api.json".to_string(),
+            oas_route: SpecRoute::default(),
+            defaults: OperationDefaults::default(),
+            spec_options: SpecOptions::default(),
         }
     }
 
@@ -335,6 +715,128 @@ where
         router.nest_into(self, path)
     }
 
+    /// Require a security scheme on every operation of this router
+    ///
+    /// Applies to routes registered on this router and routers nested into it,
+    /// unless the handler declares its own `@security`. Calling this more than
+    /// once adds alternative requirements, like repeated `@security` lines.
+    ///
+    /// ```no_run
+    /// # use rovo::{Router, rovo, routing::get, aide::axum::IntoApiResponse};
+    /// # use rovo::response::Json;
+    /// # #[rovo]
+    /// # async fn list_users() -> impl IntoApiResponse { Json(()) }
+    /// let admin: Router<()> = Router::new()
+    ///     .route("/users", get(list_users))
+    ///     .default_security("admin_token")
+    ///     .default_tag("admin");
+    /// ```
+    #[must_use]
+    pub fn default_security(mut self, scheme: impl Into<String>) -> Self {
+        self.defaults.security.push(scheme.into());
+        self
+    }
+
+    /// Document a response on every operation of this router
+    ///
+    /// Applies to routes registered on this router and routers nested into it,
+    /// unless the handler documents the same status code itself.
+    ///
+    /// ```no_run
+    /// # use rovo::{Router, rovo, routing::get, aide::axum::IntoApiResponse};
+    /// # use rovo::response::Json;
+    /// # #[rovo]
+    /// # async fn list_users() -> impl IntoApiResponse { Json(()) }
+    /// let admin: Router<()> = Router::new()
+    ///     .route("/users", get(list_users))
+    ///     .default_response::<()>(401, "Missing or invalid token")
+    ///     .default_response::<Json<String>>(500, "Internal server error");
+    /// ```
+    #[must_use]
+    pub fn default_response<R: aide::OperationOutput>(
+        mut self,
+        status: u16,
+        description: impl Into<String>,
+    ) -> Self {
+        self.defaults.responses.push(DefaultResponse {
+            status,
+            description: description.into(),
+            response: R::operation_response,
+        });
+        self
+    }
+
+    /// Define a security scheme that operations can reference with `@security <name>`
+    ///
+    /// The scheme is added to the spec's components when the router is
+    /// finished, including when it is nested into another router. Finishing
+    /// the spec panics if an operation requires a scheme that is defined
+    /// neither here nor on the `OpenApi` passed in.
+    ///
+    /// ```no_run
+    /// # use rovo::{Router, SecurityScheme, rovo, routing::get, aide::axum::IntoApiResponse};
+    /// # use rovo::response::Json;
+    /// /// Get the current user.
+    /// ///
+    /// /// # Metadata
+    /// ///
+    /// /// @security bearer_auth
+    /// #[rovo]
+    /// async fn me() -> impl IntoApiResponse { Json(()) }
+    ///
+    /// let router: Router<()> = Router::new()
+    ///     .route("/me", get(me))
+    ///     .security_scheme("bearer_auth", SecurityScheme::bearer_jwt());
+    /// ```
+    #[must_use]
+    pub fn security_scheme(
+        mut self,
+        name: impl Into<String>,
+        scheme: impl Into<SecurityScheme>,
+    ) -> Self {
+        self.spec_options
+            .security_schemes
+            .push((name.into(), scheme.into()));
+        self
+    }
+
+    /// Prefix operation IDs that are used more than once instead of rejecting them
+    ///
+    /// `#[rovo]` defaults an operation's ID to the handler's name, so handlers
+    /// with the same name in different modules collide. With this option each
+    /// duplicated ID gets a prefix when the router is finished: the handler's
+    /// module path (`api_users_get_user`) or the operation's first tag
+    /// (`users_get_user`). IDs that are still duplicated afterwards are
+    /// reported as usual.
+    ///
+    /// ```no_run
+    /// # use rovo::{OperationIdPrefix, Router};
+    /// let router: Router<()> =
+    ///     Router::new().disambiguate_operation_ids(OperationIdPrefix::ModulePath);
+    /// ```
+    #[must_use]
+    pub const fn disambiguate_operation_ids(mut self, prefix: OperationIdPrefix) -> Self {
+        self.spec_options.operation_id_prefix = Some(prefix);
+        self
+    }
+
+    /// Tag every operation of this router
+    ///
+    /// Applies to routes registered on this router and routers nested into it,
+    /// unless the handler declares its own `@tag`.
+    #[must_use]
+    pub fn default_tag(mut self, tag: impl Into<String>) -> Self {
+        self.defaults.tags.push(tag.into());
+        self
+    }
+
+    /// Apply the router's operation defaults to everything registered so far
+    fn apply_defaults(mut self) -> Self {
+        let defaults = std::mem::take(&mut self.defaults);
+        self.inner = defaults.apply(self.inner);
+        self
+    }
+
     /// Configure `OpenAPI` spec with default routes (/api.json and /api.yaml)
     ///
     /// This automatically sets up endpoints for both JSON and YAML formats.
@@ -343,14 +845,18 @@ where
     ///
     /// The `OpenAPI` spec is serialized to JSON and YAML at startup, then the
     /// original struct is dropped to minimize memory usage. Only the pre-serialized
-    /// strings are kept in memory.
+    /// strings are kept in memory, along with their compressed copies when the
+    /// `gzip` or `brotli` features are enabled.
+    ///
+    /// The endpoints send an `ETag` and support conditional requests, see
+    /// [`spec_cache_control`](Self::spec_cache_control).
     ///
     /// If you need runtime access to the `OpenApi` struct (e.g., in handlers via
     /// `Extension<Arc<OpenApi>>`), use [`finish_api_with_extension`](Self::finish_api_with_extension) instead.
     #[must_use]
     pub fn with_oas(mut self, api: OpenApi) -> Self {
         self.oas_spec = Some(api);
-        self.oas_route = "/api.json".to_string();
+        self.oas_route = SpecRoute::default();
         self
     }
 
@@ -378,7 +884,108 @@ where
             .or_else(|| route_str.strip_suffix(".yaml"))
             .or_else(|| route_str.strip_suffix(".yml"))
             .unwrap_or(&route_str);
-        self.oas_route = format!("{base_route}.json");
+        self.oas_route = SpecRoute::Extensions(format!("{base_route}.json"));
+        self
+    }
+
+    /// Configure `OpenAPI` spec served from a single route, in the format the
+    /// client asks for with `Accept`
+    ///
+    /// | `Accept` | Served |
+    /// |----------|--------|
+    /// | `application/json`, `*/*` or none | JSON |
+    /// | `application/yaml`, `application/x-yaml`, `text/yaml` | YAML |
+    /// | `application/vnd.oai.openapi+json;version=3.0` | JSON, downgraded to `OpenAPI` 3.0 |
+    /// | `application/vnd.oai.openapi;version=3.0` | YAML, downgraded to `OpenAPI` 3.0 |
+    ///
+    /// Other media types get `406 Not Acceptable`. The 3.0 downgrade rewrites
+    /// what 3.0 can express, such as `type: [T, "null"]` to `nullable: true`,
+    /// and drops the rest, for tooling that cannot read 3.1.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let router: Router<()> = Router::new().with_oas_negotiated(OpenApi::default(), "/openapi");
+    /// ```
+    #[must_use]
+    pub fn with_oas_negotiated(mut self, api: OpenApi, route: impl Into<String>) -> Self {
+        self.oas_spec = Some(api);
+        self.oas_route = SpecRoute::Negotiated(route.into());
+        self
+    }
+
+    /// Set the `Cache-Control` header of the spec endpoints, `no-cache` by default
+    ///
+    /// The spec endpoints send a strong `ETag` and answer a matching
+    /// `If-None-Match` with `304 Not Modified`, so with `no-cache` clients
+    /// revalidate cheaply on every poll. With the `gzip` and `brotli` features
+    /// the spec is also compressed once at startup and served according to
+    /// `Accept-Encoding`.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let router: Router<()> = Router::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .spec_cache_control("public, max-age=300");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not a valid header value.
+    #[must_use]
+    pub fn spec_cache_control(mut self, value: impl Into<String>) -> Self {
+        let value = value.into();
+        let header = ::axum::http::HeaderValue::try_from(value.as_str())
+            .unwrap_or_else(|_| panic!("invalid Cache-Control value: {value:?}"));
+        self.spec_options.cache_control = Some(header);
+        self
+    }
+
+    /// Validate requests against the finished spec before they reach the
+    /// handlers
+    ///
+    /// Path, query and header parameters and JSON request bodies are checked
+    /// against their schemas, including formats, bounds, patterns and enum
+    /// values. Parameters are matched by the spec's path templates, so routes
+    /// without documentation pass through unchecked. An invalid request gets
+    /// an RFC 7807 problem response listing every violation, see
+    /// [`RequestValidation`].
+    ///
+    /// ```no_run
+    /// # use rovo::{RequestValidation, Router};
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let router: Router<()> = Router::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .validate_requests(RequestValidation::new());
+    /// ```
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub fn validate_requests(mut self, validation: RequestValidation) -> Self {
+        self.spec_options.request_validation = Some(validation);
+        self
+    }
+
+    /// Check responses against the operations' documented responses
+    ///
+    /// Each response of a documented operation must have a status code listed
+    /// in `# Responses` (or covered by a range or default response), one of
+    /// the documented media types and, for JSON, a body matching the
+    /// documented schema. Mismatches are logged as `tracing` warnings, or
+    /// panic with [`ResponseValidation::panic_on_mismatch`]. The checks buffer
+    /// JSON bodies, so they are meant for tests and debug builds:
+    ///
+    /// ```no_run
+    /// # use rovo::{ResponseValidation, Router};
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let router: Router<()> = Router::new().with_oas(OpenApi::default());
+    /// #[cfg(debug_assertions)]
+    /// let router = router.validate_responses(ResponseValidation::new());
+    /// ```
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub const fn validate_responses(mut self, validation: ResponseValidation) -> Self {
+        self.spec_options.response_validation = Some(validation);
         self
     }
 
@@ -389,7 +996,7 @@ where
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas_route.url().to_string();
         self.inner = self.inner.route(
             swagger_path,
             aide::swagger::Swagger::new(&api_route).axum_route(),
@@ -404,7 +1011,7 @@ where
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas_route.url().to_string();
         self.inner = self
             .inner
             .route(redoc_path, aide::redoc::Redoc::new(&api_route).axum_route());
@@ -418,7 +1025,7 @@ where
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas_route.url().to_string();
         self.inner = self.inner.route(
             scalar_path,
             aide::scalar::Scalar::new(&api_route).axum_route(),
@@ -427,82 +1034,84 @@ where
     }
 
     /// Internal helper to wire up `OpenAPI` endpoints and extension
-    fn wire_openapi_routes(self) -> (Option<::axum::Router<S>>, Option<AideApiRouter<S>>)
+    fn wire_openapi_routes(self, checks: SpecChecks) -> Result<::axum::Router<S>, Error>
     where
         S: Clone + Send + Sync + 'static,
     {
-        if let Some(api) = self.oas_spec {
-            let oas_route = self.oas_route.clone();
+        let this = self.apply_defaults();
+        if let Some(api) = this.oas_spec {
+            let oas_route = this.oas_route;
+            let cache_control = this.spec_options.cache_control.clone().unwrap_or_else(|| {
+                ::axum::http::HeaderValue::from_static(serve::DEFAULT_CACHE_CONTROL)
+            });
 
             // Finish API first to populate it with routes
             let mut api_mut = api;
-            let axum_router = self.inner.finish_api(&mut api_mut);
-
-            // Pre-serialize once at startup to avoid cloning on each request
-            let json_bytes: ::axum::body::Bytes = serde_json::to_vec(&api_mut)
-                .expect("Failed to serialize OpenAPI spec to JSON")
-                .into();
-            let yaml_bytes: ::axum::body::Bytes = serde_yaml::to_string(&api_mut)
-                .expect("Failed to serialize OpenAPI spec to YAML")
-                .into();
-
-            // Determine base route (without extension)
-            let base_route = oas_route.strip_suffix(".json").unwrap_or(&oas_route);
-
-            // Add JSON endpoint - returns pre-serialized bytes
-            let json_for_handler = json_bytes;
-            let router_with_json = axum_router.route(
-                &oas_route,
-                ::axum::routing::get(move || {
-                    let json = json_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/json")],
-                            json,
-                        )
-                    }
-                }),
-            );
+            let axum_router = finish_spec(this.inner, &mut api_mut, this.spec_options, checks)?;
 
-            // Add YAML endpoint - returns pre-serialized bytes
-            let yaml_route = format!("{base_route}.yaml");
-            let yaml_for_handler = yaml_bytes.clone();
-            let router_with_yaml = router_with_json.route(
-                &yaml_route,
-                ::axum::routing::get(move || {
-                    let yaml = yaml_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/x-yaml")],
-                            yaml,
-                        )
-                    }
-                }),
+            // Pre-serialize once at startup to avoid cloning on each request, along
+            // with the ETags and compressed variants
+            let json = serve::SpecDocument::new(
+                spec::to_json(&api_mut)?.into(),
+                "application/json",
+                cache_control.clone(),
             );
-
-            // Add YML endpoint (alias for YAML) - reuses pre-serialized bytes
-            let yml_route = format!("{base_route}.yml");
-            let yml_for_handler = yaml_bytes;
-            let router_with_yml = router_with_yaml.route(
-                &yml_route,
-                ::axum::routing::get(move || {
-                    let yaml = yml_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/x-yaml")],
-                            yaml,
-                        )
-                    }
-                }),
+            let yaml = serve::SpecDocument::new(
+                spec::to_yaml(&api_mut)?.into(),
+                "application/x-yaml",
+                cache_control.clone(),
             );
 
+            let router_with_spec = match oas_route {
+                SpecRoute::Extensions(json_route) => {
+                    // Determine base route (without extension)
+                    let base_route = json_route.strip_suffix(".json").unwrap_or(&json_route);
+                    let yaml_route = format!("{base_route}.yaml");
+                    // YML is an alias for YAML, sharing its pre-serialized bytes
+                    let yml_route = format!("{base_route}.yml");
+
+                    axum_router
+                        .route(&json_route, spec_route(json))
+                        .route(&yaml_route, spec_route(yaml.clone()))
+                        .route(&yml_route, spec_route(yaml))
+                }
+                SpecRoute::Negotiated(route) => {
+                    let downgraded = downgrade::to_openapi_3_0(
+                        serde_json::to_value(&api_mut).map_err(|err| Error::Serialize {
+                            format: "JSON",
+                            message: err.to_string(),
+                        })?,
+                    );
+                    let spec = serve::NegotiatedSpec {
+                        json,
+                        yaml,
+                        json_3_0: serve::SpecDocument::new(
+                            spec::to_json(&downgraded)?.into(),
+                            "application/vnd.oai.openapi+json;version=3.0",
+                            cache_control.clone(),
+                        ),
+                        yaml_3_0: serve::SpecDocument::new(
+                            spec::to_yaml(&downgraded)?.into(),
+                            "application/vnd.oai.openapi;version=3.0",
+                            cache_control,
+                        ),
+                    };
+                    axum_router.route(
+                        &route,
+                        ::axum::routing::get(move |headers: ::axum::http::HeaderMap| {
+                            std::future::ready(spec.respond(&headers))
+                        }),
+                    )
+                }
+            };
+
             // No Extension layer - the OpenApi struct is dropped after serialization
             // to minimize memory usage. Use finish_api_with_extension() if you need
             // runtime access to the spec.
-            (Some(router_with_yml), None)
+            Ok(router_with_spec)
         } else {
             // No OAS spec, return the inner router
-            (None, Some(self.inner))
+            Ok(this.inner.into())
         }
     }
 
@@ -532,31 +1141,114 @@ where
     /// ```
     #[must_use]
     pub fn with_state(self, state: S) -> StatefulRouter {
+        let router = self.apply_defaults();
         StatefulRouter {
-            inner: self.inner.with_state(state),
-            oas_spec: self.oas_spec,
-            oas_route: self.oas_route,
+            inner: router.inner.with_state(state),
+            oas_spec: router.oas_spec,
+            oas_route: router.oas_route,
+            spec_options: router.spec_options,
         }
     }
 
     /// Finalize the API without state
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spec uses an undefined security scheme or cannot be
+    /// serialized. Duplicate operation IDs are only logged as a `tracing`
+    /// warning, so one handler can be mounted on several paths. Use
+    /// [`try_finish`](Self::try_finish) to handle all of these as errors.
     pub fn finish(self) -> ::axum::Router<S>
     where
         S: Clone + Send + Sync + 'static,
     {
-        let (with_oas, without_oas) = self.wire_openapi_routes();
+        self.wire_openapi_routes(SpecChecks::Finish)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        with_oas.unwrap_or_else(|| {
-            without_oas.map_or_else(
-                || unreachable!("Either with_oas or without_oas must be Some"),
-                std::convert::Into::into,
-            )
-        })
+    /// Finalize the API without state, returning spec errors instead of panicking
+    ///
+    /// When a spec is configured with [`with_oas`](Self::with_oas), the finished
+    /// spec is checked for undefined security schemes, duplicate operation IDs
+    /// and unresolved `$ref`s before it is serialized.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = match Router::<()>::new().with_oas(OpenApi::default()).try_finish() {
+    ///     Ok(app) => app,
+    ///     Err(err) => {
+    ///         eprintln!("invalid API spec: {err}");
+    ///         std::process::exit(1);
+    ///     }
+    /// };
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check or cannot be serialized.
+    pub fn try_finish(self) -> Result<::axum::Router<S>, Error>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        self.wire_openapi_routes(SpecChecks::All)
+    }
+
+    /// Build the `OpenAPI` spec without serving it
+    ///
+    /// Runs the same pipeline as [`try_finish`](Self::try_finish), including its
+    /// checks, on the spec given to [`with_oas`](Self::with_oas) or an empty one.
+    /// No listener is bound and the router's state never has to be provided.
+    /// To compare it against a checked-in file in tests, see [`testing`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check.
+    pub fn to_openapi(self) -> Result<OpenApi, Error>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let this = self.apply_defaults();
+        let mut api = this.oas_spec.unwrap_or_default();
+        // Only the spec is wanted, the routes are dropped
+        let _ = finish_spec(this.inner, &mut api, this.spec_options, SpecChecks::All)?;
+        Ok(api)
+    }
+
+    /// Write the `OpenAPI` spec to a file without serving it
+    ///
+    /// Builds the spec with [`to_openapi`](Self::to_openapi) and writes it
+    /// pretty-printed, so a small binary or a test can keep a committed copy
+    /// up to date for CI to diff.
+    ///
+    /// ```no_run
+    /// # use rovo::{Format, Router};
+    /// # use rovo::aide::openapi::OpenApi;
+    /// # fn app() -> Router<()> { Router::new().with_oas(OpenApi::default()) }
+    /// app().write_spec("openapi.json", Format::Json).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check, cannot be serialized or
+    /// the file cannot be written.
+    pub fn write_spec(self, path: impl AsRef<std::path::Path>, format: Format) -> Result<(), Error>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        spec::write(&self.to_openapi()?, path.as_ref(), format)
     }
 
     /// Finish building the API and return an axum Router for further configuration
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation requires a security scheme that is not defined.
+    /// Duplicate operation IDs are only logged as a `tracing` warning.
     pub fn finish_api(self, api: &mut aide::openapi::OpenApi) -> ::axum::Router<S> {
-        self.inner.finish_api(api)
+        let router = self.apply_defaults();
+        finish_spec(router.inner, api, router.spec_options, SpecChecks::Finish)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Finish the API with `OpenAPI` spec embedded via Extension layer
@@ -582,18 +1274,33 @@ where
     /// This keeps the full `OpenApi` struct in memory. For large APIs where you don't
     /// need runtime access, prefer using [`with_oas`](Self::with_oas) which only keeps
     /// pre-serialized strings in memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation requires a security scheme that is not defined.
+    /// Duplicate operation IDs are only logged as a `tracing` warning.
     pub fn finish_api_with_extension(self, api: aide::openapi::OpenApi) -> ::axum::Router<S>
     where
         S: Clone + Send + Sync + 'static,
     {
         let mut api_mut = api;
-        let router = self.inner.finish_api(&mut api_mut);
+        let this = self.apply_defaults();
+        let router = finish_spec(
+            this.inner,
+            &mut api_mut,
+            this.spec_options,
+            SpecChecks::Finish,
+        )
+        .unwrap_or_else(|err| panic!("{err}"));
         router.layer(Extension(Arc::new(api_mut)))
     }
 
     /// Convert into the underlying aide `ApiRouter`
     pub fn into_inner(self) -> AideApiRouter<S> {
-        self.inner
+        self.apply_defaults().inner.with_path_items(|mut item| {
+            spec::strip_module_paths_from_item(item.inner_mut());
+            item
+        })
     }
 }
 
@@ -628,7 +1335,8 @@ where
 pub struct StatefulRouter {
     inner: AideApiRouter<()>,
     oas_spec: Option<OpenApi>,
-    oas_route: String,
+    oas_route: SpecRoute,
+    spec_options: SpecOptions,
 }
 
 impl StatefulRouter {
@@ -636,16 +1344,176 @@ impl StatefulRouter {
     ///
     /// This wires up `OpenAPI` spec endpoints (JSON/YAML) if configured,
     /// then returns a ready-to-serve `axum::Router`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spec uses an undefined security scheme or cannot be
+    /// serialized. Duplicate operation IDs are only logged as a `tracing`
+    /// warning, so one handler can be mounted on several paths. Use
+    /// [`try_finish`](Self::try_finish) to handle all of these as errors.
     pub fn finish(self) -> ::axum::Router {
-        let router: Router<()> = Router {
+        self.into_router().finish()
+    }
+
+    /// Finalize into an `axum::Router`, returning spec errors instead of panicking.
+    ///
+    /// See [`Router::try_finish`] for the checks that are run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check or cannot be serialized.
+    pub fn try_finish(self) -> Result<::axum::Router, Error> {
+        self.into_router().try_finish()
+    }
+
+    /// Build the `OpenAPI` spec without serving it.
+    ///
+    /// See [`Router::to_openapi`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check.
+    pub fn to_openapi(self) -> Result<OpenApi, Error> {
+        self.into_router().to_openapi()
+    }
+
+    /// Write the `OpenAPI` spec to a file without serving it.
+    ///
+    /// See [`Router::write_spec`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check, cannot be serialized or
+    /// the file cannot be written.
+    pub fn write_spec(
+        self,
+        path: impl AsRef<std::path::Path>,
+        format: Format,
+    ) -> Result<(), Error> {
+        self.into_router().write_spec(path, format)
+    }
+
+    fn into_router(self) -> Router<()> {
+        Router {
             inner: self.inner,
             oas_spec: self.oas_spec,
             oas_route: self.oas_route,
-        };
-        router.finish()
+            defaults: OperationDefaults::default(),
+            spec_options: self.spec_options,
+        }
+    }
+}
+
+/// Settings applied to the spec when a router is finished
+#[derive(Default)]
+struct SpecOptions {
+    security_schemes: Vec<(String, SecurityScheme)>,
+    operation_id_prefix: Option<OperationIdPrefix>,
+    cache_control: Option<::axum::http::HeaderValue>,
+    #[cfg(feature = "validation")]
+    request_validation: Option<RequestValidation>,
+    #[cfg(feature = "validation")]
+    response_validation: Option<ResponseValidation>,
+}
+
+impl SpecOptions {
+    /// Take over the settings of a router nested into this one
+    fn merge(&mut self, child: Self) {
+        self.security_schemes.extend(child.security_schemes);
+        self.operation_id_prefix = self.operation_id_prefix.or(child.operation_id_prefix);
+        self.cache_control = self.cache_control.take().or(child.cache_control);
+        #[cfg(feature = "validation")]
+        {
+            self.request_validation = self.request_validation.take().or(child.request_validation);
+            self.response_validation = self
+                .response_validation
+                .take()
+                .or(child.response_validation);
+        }
+    }
+}
+
+/// Where the spec is served
+enum SpecRoute {
+    /// `{base}.json`, `{base}.yaml` and `{base}.yml`, holding the `.json` route
+    Extensions(String),
+    /// One route choosing the format from `Accept`
+    Negotiated(String),
+}
+
+impl Default for SpecRoute {
+    fn default() -> Self {
+        Self::Extensions("/api.json".to_string())
+    }
+}
+
+impl SpecRoute {
+    /// Route documentation UIs load the spec from
+    #[cfg(any(feature = "swagger", feature = "redoc", feature = "scalar"))]
+    fn url(&self) -> &str {
+        match self {
+            Self::Extensions(route) | Self::Negotiated(route) => route,
+        }
     }
 }
 
+/// `GET` route serving a spec document
+fn spec_route<S>(document: serve::SpecDocument) -> ::axum::routing::MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    ::axum::routing::get(move |headers: ::axum::http::HeaderMap| {
+        std::future::ready(document.respond(&headers))
+    })
+}
+
+/// Which checks to run on a finished spec
+#[derive(Clone, Copy)]
+enum SpecChecks {
+    /// Undefined security schemes, enforced by the panicking `finish*` methods,
+    /// which only log duplicate operation IDs
+    Finish,
+    /// Everything `try_finish` reports
+    All,
+}
+
+/// Merge the router's documentation into `api` and check the result
+fn finish_spec<S>(
+    inner: AideApiRouter<S>,
+    api: &mut OpenApi,
+    options: SpecOptions,
+    checks: SpecChecks,
+) -> Result<::axum::Router<S>, Error>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let router = inner.finish_api(api);
+    security::register(api, options.security_schemes);
+    if let Some(prefix) = options.operation_id_prefix {
+        spec::disambiguate_operation_ids(api, prefix);
+    }
+    spec::strip_module_paths(api);
+
+    match checks {
+        SpecChecks::Finish => {
+            spec::check_security(api)?;
+            spec::warn_duplicate_operation_ids(api);
+        }
+        SpecChecks::All => spec::check(api)?,
+    }
+
+    #[cfg(feature = "validation")]
+    if options.request_validation.is_some() || options.response_validation.is_some() {
+        return validation::install(
+            router,
+            api,
+            options.request_validation,
+            options.response_validation,
+        );
+    }
+    Ok(router)
+}
+
 impl<S> Default for Router<S>
 where
     S: Clone + Send + Sync + 'static,
@@ -673,6 +1541,153 @@ pub trait IntoApiMethodRouter<S = ()> {
     fn into_delete_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
     /// Convert into a PUT route with documentation
     fn into_put_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+    /// Convert into a HEAD route with documentation
+    fn into_head_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+    /// Convert into an OPTIONS route with documentation
+    fn into_options_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+    /// Convert into a TRACE route with documentation
+    fn into_trace_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+}
+
+/// HTTP method selector passed to [`DocumentedHandler`] route builders, used
+/// internally by the `#[rovo]` macro.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum __RouteMethod {
+    Get,
+    Post,
+    Patch,
+    Delete,
+    Put,
+    Head,
+    Options,
+    Trace,
+}
+
+/// Handle to a documented handler that cannot be represented by a unit struct.
+///
+/// `#[rovo]` on an `impl` block generates one of these as an associated const
+/// for every `#[rovo]` method inside it, so `Handlers::get_user` can be passed
+/// to the routing functions just like a free-standing handler. Generic
+/// handlers instead get a function returning one, so `list::<PgRepo>` (or just
+/// `list`, when the state type pins the generics down) can be routed.
+///
+/// # Example
+/// ```no_run
+/// use rovo::{Router, rovo, routing::get, aide::axum::IntoApiResponse};
+/// use rovo::response::Json;
+///
+/// struct Users;
+///
+/// #[rovo]
+/// impl Users {
+///     /// List users.
+///     #[rovo]
+///     async fn list() -> impl IntoApiResponse { Json(()) }
+/// }
+///
+/// /// Describe a type.
+/// #[rovo]
+/// async fn describe<T: 'static>() -> impl IntoApiResponse {
+///     Json(std::any::type_name::<T>())
+/// }
+///
+/// let router: Router<()> = Router::new()
+///     .route("/users", get(Users::list))
+///     .route("/describe", get(describe::<u8>));
+/// ```
+pub struct DocumentedHandler<S = ()> {
+    route: fn(__RouteMethod) -> aide::axum::routing::ApiMethodRouter<S>,
+}
+
+impl<S> DocumentedHandler<S> {
+    #[doc(hidden)]
+    pub const fn __new(
+        route: fn(__RouteMethod) -> aide::axum::routing::ApiMethodRouter<S>,
+    ) -> Self {
+        Self { route }
+    }
+}
+
+impl<S> Clone for DocumentedHandler<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for DocumentedHandler<S> {}
+
+/// Generic `#[rovo]` handlers expand to functions returning a
+/// [`DocumentedHandler`], which are routed by calling them.
+impl<S, F> IntoApiMethodRouter<S> for F
+where
+    F: FnOnce() -> DocumentedHandler<S>,
+{
+    fn into_get_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_get_route()
+    }
+
+    fn into_post_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_post_route()
+    }
+
+    fn into_patch_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_patch_route()
+    }
+
+    fn into_delete_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_delete_route()
+    }
+
+    fn into_put_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_put_route()
+    }
+
+    fn into_head_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_head_route()
+    }
+
+    fn into_options_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_options_route()
+    }
+
+    fn into_trace_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        self().into_trace_route()
+    }
+}
+
+impl<S> IntoApiMethodRouter<S> for DocumentedHandler<S> {
+    fn into_get_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Get)
+    }
+
+    fn into_post_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Post)
+    }
+
+    fn into_patch_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Patch)
+    }
+
+    fn into_delete_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Delete)
+    }
+
+    fn into_put_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Put)
+    }
+
+    fn into_head_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Head)
+    }
+
+    fn into_options_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Options)
+    }
+
+    fn into_trace_route(self) -> aide::axum::routing::ApiMethodRouter<S> {
+        (self.route)(__RouteMethod::Trace)
+    }
 }
 
 /// Wrapper around `ApiMethodRouter` that provides method chaining for documented handlers.
@@ -767,6 +1782,36 @@ where
             inner: self.inner.merge(handler.into_put_route()),
         }
     }
+
+    /// Chain a HEAD handler
+    pub fn head<H>(self, handler: H) -> Self
+    where
+        H: IntoApiMethodRouter<S>,
+    {
+        Self {
+            inner: self.inner.merge(handler.into_head_route()),
+        }
+    }
+
+    /// Chain an OPTIONS handler
+    pub fn options<H>(self, handler: H) -> Self
+    where
+        H: IntoApiMethodRouter<S>,
+    {
+        Self {
+            inner: self.inner.merge(handler.into_options_route()),
+        }
+    }
+
+    /// Chain a TRACE handler
+    pub fn trace<H>(self, handler: H) -> Self
+    where
+        H: IntoApiMethodRouter<S>,
+    {
+        Self {
+            inner: self.inner.merge(handler.into_trace_route()),
+        }
+    }
 }
 
 impl<S> From<ApiMethodRouter<S>> for aide::axum::routing::ApiMethodRouter<S> {
@@ -877,6 +1922,33 @@ pub mod routing {
     {
         ApiMethodRouter::new(handler.into_put_route())
     }
+
+    /// Create a HEAD route with documentation from a `#[rovo]` decorated handler.
+    pub fn head<S, H>(handler: H) -> ApiMethodRouter<S>
+    where
+        H: IntoApiMethodRouter<S>,
+        S: Clone + Send + Sync + 'static,
+    {
+        ApiMethodRouter::new(handler.into_head_route())
+    }
+
+    /// Create an OPTIONS route with documentation from a `#[rovo]` decorated handler.
+    pub fn options<S, H>(handler: H) -> ApiMethodRouter<S>
+    where
+        H: IntoApiMethodRouter<S>,
+        S: Clone + Send + Sync + 'static,
+    {
+        ApiMethodRouter::new(handler.into_options_route())
+    }
+
+    /// Create a TRACE route with documentation from a `#[rovo]` decorated handler.
+    pub fn trace<S, H>(handler: H) -> ApiMethodRouter<S>
+    where
+        H: IntoApiMethodRouter<S>,
+        S: Clone + Send + Sync + 'static,
+    {
+        ApiMethodRouter::new(handler.into_trace_route())
+    }
 }
 
 /// Re-exports from aide's axum integration for convenience.
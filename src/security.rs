@@ -0,0 +1,270 @@
+//! Security scheme definitions registered with [`Router::security_scheme`](crate::Router::security_scheme).
+
+use aide::openapi::{ApiKeyLocation, OAuth2Flow, OAuth2Flows, OpenApi, ReferenceOr};
+
+use crate::error::UndefinedSecurityScheme;
+use crate::spec;
+
+/// A security scheme that operations can reference with `@security <name>`.
+///
+/// Build one with the constructors below and register it on a router with
+/// [`Router::security_scheme`](crate::Router::security_scheme). Anything not
+/// covered by them can be converted from aide's
+/// [`SecurityScheme`](aide::openapi::SecurityScheme) with `From`.
+///
+/// # Example
+/// ```no_run
+/// use rovo::{Router, SecurityScheme};
+///
+/// let router: Router<()> = Router::new()
+///     .security_scheme("bearer_auth", SecurityScheme::bearer_jwt())
+///     .security_scheme(
+///         "api_key",
+///         SecurityScheme::api_key_header("X-Api-Key").description("Issued per client"),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityScheme(aide::openapi::SecurityScheme);
+
+// aide doesn't re-export `IndexMap`, so empty extensions come from `Default`
+#[allow(clippy::default_trait_access)]
+impl SecurityScheme {
+    /// HTTP bearer authentication with a JWT (`Authorization: Bearer <token>`)
+    #[must_use]
+    pub fn bearer_jwt() -> Self {
+        Self::http("bearer", Some("JWT".to_string()))
+    }
+
+    /// HTTP bearer authentication with an opaque token
+    #[must_use]
+    pub fn bearer() -> Self {
+        Self::http("bearer", None)
+    }
+
+    /// HTTP basic authentication
+    #[must_use]
+    pub fn basic() -> Self {
+        Self::http("basic", None)
+    }
+
+    /// API key sent in the given request header
+    #[must_use]
+    pub fn api_key_header(name: impl Into<String>) -> Self {
+        Self::api_key(ApiKeyLocation::Header, name.into())
+    }
+
+    /// API key sent in the given query parameter
+    #[must_use]
+    pub fn api_key_query(name: impl Into<String>) -> Self {
+        Self::api_key(ApiKeyLocation::Query, name.into())
+    }
+
+    /// API key sent in the given cookie
+    #[must_use]
+    pub fn api_key_cookie(name: impl Into<String>) -> Self {
+        Self::api_key(ApiKeyLocation::Cookie, name.into())
+    }
+
+    /// `OAuth2` authorization code flow, with `(scope, description)` pairs
+    #[must_use]
+    pub fn oauth2_authorization_code<N, D>(
+        authorization_url: impl Into<String>,
+        token_url: impl Into<String>,
+        scopes: impl IntoIterator<Item = (N, D)>,
+    ) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+    {
+        Self::oauth2(OAuth2Flows {
+            authorization_code: Some(OAuth2Flow::AuthorizationCode {
+                authorization_url: authorization_url.into(),
+                token_url: token_url.into(),
+                refresh_url: None,
+                scopes: collect_scopes(scopes),
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// `OAuth2` client credentials flow, with `(scope, description)` pairs
+    #[must_use]
+    pub fn oauth2_client_credentials<N, D>(
+        token_url: impl Into<String>,
+        scopes: impl IntoIterator<Item = (N, D)>,
+    ) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+    {
+        Self::oauth2(OAuth2Flows {
+            client_credentials: Some(OAuth2Flow::ClientCredentials {
+                token_url: token_url.into(),
+                refresh_url: None,
+                scopes: collect_scopes(scopes),
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// `OAuth2` implicit flow, with `(scope, description)` pairs
+    #[must_use]
+    pub fn oauth2_implicit<N, D>(
+        authorization_url: impl Into<String>,
+        scopes: impl IntoIterator<Item = (N, D)>,
+    ) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+    {
+        Self::oauth2(OAuth2Flows {
+            implicit: Some(OAuth2Flow::Implicit {
+                authorization_url: authorization_url.into(),
+                refresh_url: None,
+                scopes: collect_scopes(scopes),
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// `OAuth2` resource owner password flow, with `(scope, description)` pairs
+    #[must_use]
+    pub fn oauth2_password<N, D>(
+        token_url: impl Into<String>,
+        scopes: impl IntoIterator<Item = (N, D)>,
+    ) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+    {
+        Self::oauth2(OAuth2Flows {
+            password: Some(OAuth2Flow::Password {
+                token_url: token_url.into(),
+                refresh_url: None,
+                scopes: collect_scopes(scopes),
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// `OpenID Connect` discovery at the given URL
+    #[must_use]
+    pub fn open_id_connect(url: impl Into<String>) -> Self {
+        Self(aide::openapi::SecurityScheme::OpenIdConnect {
+            open_id_connect_url: url.into(),
+            description: None,
+            extensions: Default::default(),
+        })
+    }
+
+    /// Set the description shown for the scheme
+    #[must_use]
+    pub fn description(mut self, text: impl Into<String>) -> Self {
+        let text = Some(text.into());
+        match &mut self.0 {
+            aide::openapi::SecurityScheme::ApiKey { description, .. }
+            | aide::openapi::SecurityScheme::Http { description, .. }
+            | aide::openapi::SecurityScheme::OAuth2 { description, .. }
+            | aide::openapi::SecurityScheme::OpenIdConnect { description, .. }
+            | aide::openapi::SecurityScheme::MutualTls { description, .. } => {
+                *description = text;
+            }
+        }
+        self
+    }
+
+    /// Convert into aide's [`SecurityScheme`](aide::openapi::SecurityScheme)
+    #[must_use]
+    pub fn into_inner(self) -> aide::openapi::SecurityScheme {
+        self.0
+    }
+
+    fn http(scheme: &str, bearer_format: Option<String>) -> Self {
+        Self(aide::openapi::SecurityScheme::Http {
+            scheme: scheme.to_string(),
+            bearer_format,
+            description: None,
+            extensions: Default::default(),
+        })
+    }
+
+    fn api_key(location: ApiKeyLocation, name: String) -> Self {
+        Self(aide::openapi::SecurityScheme::ApiKey {
+            location,
+            name,
+            description: None,
+            extensions: Default::default(),
+        })
+    }
+
+    fn oauth2(flows: OAuth2Flows) -> Self {
+        Self(aide::openapi::SecurityScheme::OAuth2 {
+            flows,
+            description: None,
+            extensions: Default::default(),
+        })
+    }
+}
+
+impl From<aide::openapi::SecurityScheme> for SecurityScheme {
+    fn from(scheme: aide::openapi::SecurityScheme) -> Self {
+        Self(scheme)
+    }
+}
+
+fn collect_scopes<N, D, T>(scopes: impl IntoIterator<Item = (N, D)>) -> T
+where
+    N: Into<String>,
+    D: Into<String>,
+    T: FromIterator<(String, String)>,
+{
+    scopes
+        .into_iter()
+        .map(|(name, description)| (name.into(), description.into()))
+        .collect()
+}
+
+/// Add the registered schemes to the spec's components
+pub fn register(api: &mut OpenApi, schemes: Vec<(String, SecurityScheme)>) {
+    if schemes.is_empty() {
+        return;
+    }
+
+    let components = api.components.get_or_insert_with(Default::default);
+    for (name, scheme) in schemes {
+        components
+            .security_schemes
+            .insert(name, ReferenceOr::Item(scheme.0));
+    }
+}
+
+/// Every security scheme required by the spec but not defined in its components
+pub fn undefined_schemes(api: &OpenApi) -> Vec<UndefinedSecurityScheme> {
+    let is_defined = |name: &str| {
+        api.components
+            .as_ref()
+            .is_some_and(|components| components.security_schemes.contains_key(name))
+    };
+
+    let global = api
+        .security
+        .iter()
+        .flat_map(|requirement| requirement.keys())
+        .map(|name| (None, name));
+    let per_operation = spec::operations(api).flat_map(|(operation_ref, operation)| {
+        operation
+            .security
+            .iter()
+            .flat_map(|requirement| requirement.keys())
+            .map(move |name| (Some(operation_ref.clone()), name))
+    });
+
+    global
+        .chain(per_operation)
+        .filter(|(_, name)| !is_defined(name))
+        .map(|(operation, name)| UndefinedSecurityScheme {
+            scheme: name.clone(),
+            operation,
+        })
+        .collect()
+}
@@ -0,0 +1,273 @@
+//! Serving the pre-serialized spec with `ETag`s, conditional GET and compression.
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::http::header::{self, HeaderMap, HeaderValue};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// `Cache-Control` sent with the spec unless configured otherwise: clients may
+/// keep a copy but revalidate it with `If-None-Match` before every use
+pub const DEFAULT_CACHE_CONTROL: &str = "no-cache";
+
+/// A serialized spec document, with its encodings computed once at startup
+#[derive(Clone)]
+pub struct SpecDocument(Arc<Representations>);
+
+struct Representations {
+    content_type: &'static str,
+    cache_control: HeaderValue,
+    identity: Representation,
+    gzip: Option<Representation>,
+    brotli: Option<Representation>,
+}
+
+struct Representation {
+    body: Bytes,
+    etag: HeaderValue,
+}
+
+impl SpecDocument {
+    /// Precompute the `ETag`s and compressed variants of a document
+    pub fn new(body: Bytes, content_type: &'static str, cache_control: HeaderValue) -> Self {
+        let hash = format!("{:x}-{:016x}", body.len(), fnv1a(&body));
+        let representation = |body: Bytes, suffix: &str| Representation {
+            body,
+            etag: HeaderValue::try_from(format!("\"{hash}{suffix}\""))
+                .expect("ETag is a quoted hex string"),
+        };
+
+        let gzip = compress_gzip(&body).map(|body| representation(body, "-gzip"));
+        let brotli = compress_brotli(&body).map(|body| representation(body, "-br"));
+
+        Self(Arc::new(Representations {
+            content_type,
+            cache_control,
+            identity: representation(body, ""),
+            gzip,
+            brotli,
+        }))
+    }
+
+    /// Answer a `GET` for the document, honouring `Accept-Encoding` and `If-None-Match`
+    pub fn respond(&self, request_headers: &HeaderMap) -> Response {
+        let document = &*self.0;
+        let (representation, encoding) = document.negotiate(request_headers);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ETAG, representation.etag.clone());
+        headers.insert(header::CACHE_CONTROL, document.cache_control.clone());
+        if document.gzip.is_some() || document.brotli.is_some() {
+            headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+        }
+
+        if is_not_modified(request_headers, &representation.etag) {
+            return (StatusCode::NOT_MODIFIED, headers).into_response();
+        }
+
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(document.content_type),
+        );
+        if let Some(encoding) = encoding {
+            headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static(encoding));
+        }
+        (headers, representation.body.clone()).into_response()
+    }
+}
+
+/// The spec in every format and version, served from one route according to `Accept`
+#[derive(Clone)]
+pub struct NegotiatedSpec {
+    pub json: SpecDocument,
+    pub yaml: SpecDocument,
+    pub json_3_0: SpecDocument,
+    pub yaml_3_0: SpecDocument,
+}
+
+/// Media types the negotiated route can answer, for the `406` body
+const SUPPORTED_MEDIA_TYPES: &str = "application/json, application/yaml, \
+     application/vnd.oai.openapi+json;version=3.0, application/vnd.oai.openapi;version=3.0";
+
+impl NegotiatedSpec {
+    /// Answer a `GET` with the document the client prefers, or `406 Not Acceptable`
+    pub fn respond(&self, request_headers: &HeaderMap) -> Response {
+        let Some(document) = self.negotiate(request_headers) else {
+            return (
+                StatusCode::NOT_ACCEPTABLE,
+                [(header::VARY, HeaderValue::from_static("accept"))],
+                format!("Supported media types: {SUPPORTED_MEDIA_TYPES}"),
+            )
+                .into_response();
+        };
+
+        let mut response = document.respond(request_headers);
+        response
+            .headers_mut()
+            .append(header::VARY, HeaderValue::from_static("accept"));
+        response
+    }
+
+    /// The document matching the highest-quality media range in `Accept`,
+    /// the earliest one winning ties; JSON without an `Accept` header
+    fn negotiate(&self, headers: &HeaderMap) -> Option<&SpecDocument> {
+        let mut ranges: Vec<(f32, &str)> = headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter(|range| !range.trim().is_empty())
+            .map(|range| (q_value(range), range))
+            .collect();
+        if ranges.is_empty() {
+            return Some(&self.json);
+        }
+
+        ranges.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        ranges
+            .into_iter()
+            .filter(|(quality, _)| *quality > 0.0)
+            .find_map(|(_, range)| self.document_for(range))
+    }
+
+    fn document_for(&self, range: &str) -> Option<&SpecDocument> {
+        let mut parts = range.split(';');
+        let media_type = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let version = parts
+            .filter_map(|param| param.trim().strip_prefix("version="))
+            .map(|version| version.trim().trim_matches('"'))
+            .next();
+        let is_3_0 = match version {
+            None => false,
+            Some(version) if version == "3.0" || version.starts_with("3.0.") => true,
+            Some(version) if version == "3.1" || version.starts_with("3.1.") => false,
+            Some(_) => return None,
+        };
+
+        let (json, yaml) = if is_3_0 {
+            (&self.json_3_0, &self.yaml_3_0)
+        } else {
+            (&self.json, &self.yaml)
+        };
+        match media_type.as_str() {
+            "application/vnd.oai.openapi+json" => Some(json),
+            "application/vnd.oai.openapi" => Some(yaml),
+            "application/json" | "application/*" | "*/*" if !is_3_0 => Some(json),
+            "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml" | "text/*"
+                if !is_3_0 =>
+            {
+                Some(yaml)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `q` parameter of an `Accept` media range, 1 if absent
+fn q_value(range: &str) -> f32 {
+    range
+        .split(';')
+        .skip(1)
+        .filter_map(|param| param.trim().strip_prefix("q="))
+        .find_map(|q| q.trim().parse().ok())
+        .unwrap_or(1.0)
+}
+
+impl Representations {
+    /// Pick the representation the client prefers, brotli winning ties
+    fn negotiate(&self, headers: &HeaderMap) -> (&Representation, Option<&'static str>) {
+        let candidates = [(self.brotli.as_ref(), "br"), (self.gzip.as_ref(), "gzip")];
+
+        let mut best: Option<(&Representation, &'static str, f32)> = None;
+        for (representation, coding) in candidates {
+            let Some(representation) = representation else {
+                continue;
+            };
+            let quality = accepted_quality(headers, coding);
+            if quality > 0.0 && best.is_none_or(|(_, _, best_quality)| quality > best_quality) {
+                best = Some((representation, coding, quality));
+            }
+        }
+
+        best.map_or((&self.identity, None), |(representation, coding, _)| {
+            (representation, Some(coding))
+        })
+    }
+}
+
+/// Quality the client's `Accept-Encoding` gives a content coding, 0 if not accepted
+fn accepted_quality(headers: &HeaderMap, coding: &str) -> f32 {
+    let mut wildcard = None;
+    for value in headers.get_all(header::ACCEPT_ENCODING) {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        for item in value.split(',') {
+            let name = item.split(';').next().unwrap_or_default().trim();
+            let quality = q_value(item);
+
+            if name.eq_ignore_ascii_case(coding) {
+                return quality;
+            }
+            if name == "*" {
+                wildcard = Some(quality);
+            }
+        }
+    }
+    wildcard.unwrap_or(0.0)
+}
+
+/// Whether `If-None-Match` lists the current `ETag`, compared weakly as RFC 9110 requires
+fn is_not_modified(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let etag = etag.as_bytes();
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag).as_bytes() == etag)
+}
+
+/// 64-bit FNV-1a, stable across builds so `ETag`s survive restarts
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(feature = "gzip")]
+fn compress_gzip(body: &[u8]) -> Option<Bytes> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(body).ok()?;
+    encoder.finish().ok().map(Bytes::from)
+}
+
+#[cfg(not(feature = "gzip"))]
+#[allow(clippy::unnecessary_wraps)]
+const fn compress_gzip(_body: &[u8]) -> Option<Bytes> {
+    None
+}
+
+#[cfg(feature = "brotli")]
+fn compress_brotli(body: &[u8]) -> Option<Bytes> {
+    use std::io::Write;
+
+    // Quality 9 compresses multi-megabyte specs in well under a second,
+    // where the maximum of 11 takes several
+    let mut compressed = Vec::new();
+    {
+        let mut encoder = brotli::CompressorWriter::new(&mut compressed, 4096, 9, 22);
+        encoder.write_all(body).ok()?;
+    }
+    Some(Bytes::from(compressed))
+}
+
+#[cfg(not(feature = "brotli"))]
+#[allow(clippy::unnecessary_wraps)]
+const fn compress_brotli(_body: &[u8]) -> Option<Bytes> {
+    None
+}
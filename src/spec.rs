@@ -0,0 +1,285 @@
+//! Checks and serialization of a router's finished `OpenAPI` spec.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use aide::openapi::{OpenApi, Operation, PathItem, ReferenceOr};
+
+use crate::error::{DuplicateOperationId, Error, OperationRef};
+use crate::security;
+
+/// Serialization format of a spec written with [`Router::write_spec`](crate::Router::write_spec)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// Pretty-printed JSON
+    Json,
+    /// YAML
+    Yaml,
+}
+
+pub fn to_json(value: &impl serde::Serialize) -> Result<Vec<u8>, Error> {
+    serde_json::to_vec(value).map_err(|err| Error::Serialize {
+        format: "JSON",
+        message: err.to_string(),
+    })
+}
+
+pub fn to_yaml(value: &impl serde::Serialize) -> Result<Vec<u8>, Error> {
+    serde_yaml::to_string(value)
+        .map(String::into_bytes)
+        .map_err(|err| Error::Serialize {
+            format: "YAML",
+            message: err.to_string(),
+        })
+}
+
+/// Write the spec to a file, pretty-printed so diffs of committed specs stay readable
+pub fn write(api: &OpenApi, path: &Path, format: Format) -> Result<(), Error> {
+    let mut bytes = match format {
+        Format::Json => serde_json::to_vec_pretty(api).map_err(|err| Error::Serialize {
+            format: "JSON",
+            message: err.to_string(),
+        })?,
+        Format::Yaml => to_yaml(api)?,
+    };
+    if !bytes.ends_with(b"\n") {
+        bytes.push(b'\n');
+    }
+
+    std::fs::write(path, bytes).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Every operation in the spec, in path order
+pub fn operations(api: &OpenApi) -> impl Iterator<Item = (OperationRef, &Operation)> {
+    api.paths
+        .iter()
+        .flat_map(|paths| paths.iter())
+        .filter_map(|(path, item)| match item {
+            ReferenceOr::Item(item) => Some((path, item)),
+            ReferenceOr::Reference { .. } => None,
+        })
+        .flat_map(|(path, item)| {
+            item.iter().map(move |(method, operation)| {
+                let operation_ref = OperationRef {
+                    method: method.to_uppercase(),
+                    path: path.clone(),
+                };
+                (operation_ref, operation)
+            })
+        })
+}
+
+/// How [`Router::disambiguate_operation_ids`](crate::Router::disambiguate_operation_ids)
+/// prefixes operation IDs that are used more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OperationIdPrefix {
+    /// The handler's module path without the crate name, e.g.
+    /// `api_users_get_user` for `get_user` in `my_app::api::users`
+    ModulePath,
+    /// The operation's first tag, e.g. `users_get_user`
+    FirstTag,
+}
+
+/// Prefix every operation ID that is used more than once, where a prefix is known
+pub fn disambiguate_operation_ids(api: &mut OpenApi, prefix: OperationIdPrefix) {
+    let duplicated: HashSet<String> = duplicate_operation_ids(api)
+        .into_iter()
+        .map(|duplicate| duplicate.id)
+        .collect();
+    if duplicated.is_empty() {
+        return;
+    }
+
+    for operation in operations_mut(api) {
+        let Some(id) = operation.operation_id.as_deref() else {
+            continue;
+        };
+        if !duplicated.contains(id) {
+            continue;
+        }
+
+        let prefix = match prefix {
+            OperationIdPrefix::ModulePath => module_path(operation).map(module_prefix),
+            OperationIdPrefix::FirstTag => operation.tags.first().map(|tag| identifier(tag)),
+        };
+        if let Some(prefix) = prefix.filter(|prefix| !prefix.is_empty()) {
+            operation.operation_id = Some(format!("{prefix}_{id}"));
+        }
+    }
+}
+
+/// Remove the module path recorded by the `#[rovo]` macro from every operation
+pub fn strip_module_paths(api: &mut OpenApi) {
+    for operation in operations_mut(api) {
+        operation
+            .extensions
+            .shift_remove(crate::__MODULE_PATH_EXTENSION);
+    }
+}
+
+/// Remove the module path recorded by the `#[rovo]` macro from a path item's operations
+pub fn strip_module_paths_from_item(item: &mut PathItem) {
+    for (_, operation) in aide::util::iter_operations_mut(item) {
+        operation
+            .extensions
+            .shift_remove(crate::__MODULE_PATH_EXTENSION);
+    }
+}
+
+fn operations_mut(api: &mut OpenApi) -> impl Iterator<Item = &mut Operation> {
+    api.paths
+        .iter_mut()
+        .flat_map(|paths| paths.paths.values_mut())
+        .filter_map(|item| match item {
+            ReferenceOr::Item(item) => Some(item),
+            ReferenceOr::Reference { .. } => None,
+        })
+        .flat_map(|item| aide::util::iter_operations_mut(item).map(|(_, operation)| operation))
+}
+
+fn module_path(operation: &Operation) -> Option<&str> {
+    operation
+        .extensions
+        .get(crate::__MODULE_PATH_EXTENSION)
+        .and_then(serde_json::Value::as_str)
+}
+
+/// `my_app::api::users` becomes `api_users`, a crate root keeps the crate name
+pub fn module_prefix(path: &str) -> String {
+    let segments: Vec<&str> = path.split("::").collect();
+    let segments = if segments.len() > 1 {
+        &segments[1..]
+    } else {
+        &segments[..]
+    };
+    identifier(&segments.join("_"))
+}
+
+/// Replace everything but ASCII letters, digits and underscores with `_`
+fn identifier(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Check that every security scheme used by the spec is defined
+pub fn check_security(api: &OpenApi) -> Result<(), Error> {
+    let undefined = security::undefined_schemes(api);
+    if undefined.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::UndefinedSecuritySchemes(undefined))
+    }
+}
+
+/// Check that no operation ID is used more than once
+pub fn check_operation_ids(api: &OpenApi) -> Result<(), Error> {
+    let duplicates = duplicate_operation_ids(api);
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::DuplicateOperationIds(duplicates))
+    }
+}
+
+/// Log operation IDs used more than once as a warning, for the `finish*`
+/// methods that tolerate them
+pub fn warn_duplicate_operation_ids(api: &OpenApi) {
+    let duplicates = duplicate_operation_ids(api);
+    if !duplicates.is_empty() {
+        tracing::warn!(
+            "{}\nhelp: use try_finish() to reject them, or disambiguate_operation_ids() to prefix them",
+            Error::DuplicateOperationIds(duplicates)
+        );
+    }
+}
+
+/// Check a finished spec for problems that break clients and code generators
+pub fn check(api: &OpenApi) -> Result<(), Error> {
+    check_security(api)?;
+    check_operation_ids(api)?;
+
+    let unresolved = unresolved_references(api)?;
+    if !unresolved.is_empty() {
+        return Err(Error::UnresolvedReferences(unresolved));
+    }
+
+    Ok(())
+}
+
+/// Operation IDs used by more than one operation, in order of first use
+pub fn duplicate_operation_ids(api: &OpenApi) -> Vec<DuplicateOperationId> {
+    let mut by_id: Vec<DuplicateOperationId> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+
+    for (operation_ref, operation) in operations(api) {
+        let Some(id) = operation.operation_id.as_deref() else {
+            continue;
+        };
+        if let Some(&i) = index.get(id) {
+            by_id[i].operations.push(operation_ref);
+        } else {
+            index.insert(id, by_id.len());
+            by_id.push(DuplicateOperationId {
+                id: id.to_string(),
+                operations: vec![operation_ref],
+            });
+        }
+    }
+
+    by_id.retain(|duplicate| duplicate.operations.len() > 1);
+    by_id
+}
+
+/// Local `$ref`s (`#/...`) that don't resolve within the spec
+fn unresolved_references(api: &OpenApi) -> Result<Vec<String>, Error> {
+    let document = serde_json::to_value(api).map_err(|err| Error::Serialize {
+        format: "JSON",
+        message: err.to_string(),
+    })?;
+
+    let mut unresolved = Vec::new();
+    collect_unresolved(&document, &document, &mut unresolved);
+    unresolved.sort();
+    unresolved.dedup();
+    Ok(unresolved)
+}
+
+fn collect_unresolved(
+    document: &serde_json::Value,
+    value: &serde_json::Value,
+    out: &mut Vec<String>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                if key == "$ref" {
+                    if let Some(pointer) = child.as_str().and_then(|r| r.strip_prefix('#')) {
+                        if document.pointer(pointer).is_none() {
+                            out.push(format!("#{pointer}"));
+                        }
+                    }
+                } else {
+                    collect_unresolved(document, child, out);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_unresolved(document, item, out);
+            }
+        }
+        _ => {}
+    }
+}
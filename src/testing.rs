@@ -0,0 +1,348 @@
+//! Snapshot testing of the generated `OpenAPI` spec.
+//!
+//! [`assert_spec_snapshot!`](crate::assert_spec_snapshot) compares a router's
+//! spec against a checked-in file, so spec changes show up in code review:
+//!
+//! ```no_run
+//! # use rovo::Router;
+//! # use rovo::aide::openapi::OpenApi;
+//! # fn api_router() -> Router<()> { Router::new().with_oas(OpenApi::default()) }
+//! #[test]
+//! fn openapi_spec() {
+//!     rovo::testing::assert_spec_snapshot!(api_router(), "openapi.json");
+//! }
+//! ```
+//!
+//! On a mismatch the assertion lists the operations, schemas and other parts
+//! of the spec that were added, removed or changed. Run the tests with
+//! `ROVO_UPDATE_SNAPSHOTS=1` to write the new spec to the file instead.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use aide::openapi::OpenApi;
+use serde_json::Value;
+
+use crate::diff::{self, is_method};
+use crate::spec::{self, Format};
+use crate::{Error, Router, StatefulRouter};
+
+/// Environment variable that makes snapshot assertions update their files
+pub const UPDATE_ENV: &str = "ROVO_UPDATE_SNAPSHOTS";
+
+/// Most changed fields listed per operation or schema
+const MAX_FIELD_CHANGES: usize = 10;
+
+/// Longest value shown in a diff before it is shortened
+const MAX_VALUE_LEN: usize = 60;
+
+#[doc(inline)]
+pub use crate::assert_spec_snapshot;
+
+/// Anything a spec snapshot can be taken of
+pub trait IntoSpec {
+    /// Build the finished spec
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the spec fails a check.
+    fn into_spec(self) -> Result<OpenApi, Error>;
+}
+
+impl<S> IntoSpec for Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn into_spec(self) -> Result<OpenApi, Error> {
+        self.to_openapi()
+    }
+}
+
+impl IntoSpec for StatefulRouter {
+    fn into_spec(self) -> Result<OpenApi, Error> {
+        self.to_openapi()
+    }
+}
+
+impl IntoSpec for OpenApi {
+    fn into_spec(self) -> Result<OpenApi, Error> {
+        Ok(self)
+    }
+}
+
+/// Assert that a router's spec matches a checked-in snapshot file.
+///
+/// The path is relative to the crate's `Cargo.toml`; `.yaml` and `.yml` files
+/// hold YAML, anything else JSON. The comparison is semantic, so formatting and
+/// key order don't matter. With `ROVO_UPDATE_SNAPSHOTS=1` a missing or
+/// outdated file is written instead of failing.
+///
+/// ```no_run
+/// # use rovo::Router;
+/// # use rovo::aide::openapi::OpenApi;
+/// # let router: Router<()> = Router::new().with_oas(OpenApi::default());
+/// rovo::testing::assert_spec_snapshot!(router, "tests/snapshots/openapi.yaml");
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! assert_spec_snapshot {
+    ($spec:expr, $path:expr $(,)?) => {
+        $crate::testing::assert_snapshot(
+            $spec,
+            ::std::path::Path::new(::core::env!("CARGO_MANIFEST_DIR")).join($path),
+            $crate::testing::update_requested(),
+        )
+    };
+}
+
+/// Assert that a spec matches the snapshot file at `path`, see
+/// [`assert_spec_snapshot!`](crate::assert_spec_snapshot), which passes
+/// [`update_requested`] as `update`.
+///
+/// # Panics
+///
+/// Panics with a diff if the spec differs from the snapshot, if the snapshot
+/// is missing, or if the spec fails its checks, unless `update` is set, in
+/// which case the snapshot is rewritten.
+#[track_caller]
+pub fn assert_snapshot(spec: impl IntoSpec, path: impl AsRef<Path>, update: bool) {
+    let path = path.as_ref();
+    let api = match spec.into_spec() {
+        Ok(api) => api,
+        Err(err) => panic!("failed to build the OpenAPI spec: {err}"),
+    };
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml" | "yml") => Format::Yaml,
+        _ => Format::Json,
+    };
+
+    let actual = match serde_json::to_value(&api) {
+        Ok(actual) => actual,
+        Err(err) => panic!("failed to serialize the OpenAPI spec: {err}"),
+    };
+    let expected = match std::fs::read_to_string(path) {
+        Ok(contents) => match parse(&contents, format) {
+            Ok(expected) => Some(expected),
+            Err(err) => panic!("failed to parse snapshot {}: {err}", path.display()),
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => panic!("failed to read snapshot {}: {err}", path.display()),
+    };
+
+    if expected.as_ref() == Some(&actual) {
+        return;
+    }
+
+    if update {
+        if let Err(err) = spec::write(&api, path, format) {
+            panic!("{err}");
+        }
+        return;
+    }
+
+    match expected {
+        None => panic!(
+            "OpenAPI snapshot {} does not exist\nhelp: run with {UPDATE_ENV}=1 to create it",
+            path.display()
+        ),
+        Some(expected) => panic!(
+            "OpenAPI spec does not match snapshot {}\n\n{}\nhelp: run with {UPDATE_ENV}=1 to update it",
+            path.display(),
+            diff(&expected, &actual)
+        ),
+    }
+}
+
+fn parse(contents: &str, format: Format) -> Result<Value, String> {
+    match format {
+        Format::Yaml => serde_yaml::from_str(contents).map_err(|err| err.to_string()),
+        Format::Json => serde_json::from_str(contents).map_err(|err| err.to_string()),
+    }
+}
+
+/// Whether [`UPDATE_ENV`] is set to something other than empty or `0`
+#[must_use]
+pub fn update_requested() -> bool {
+    std::env::var_os(UPDATE_ENV).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Describe the differences between two serialized specs, grouped into
+/// operations, schemas and everything else
+fn diff(expected: &Value, actual: &Value) -> String {
+    let mut out = String::new();
+
+    let operations = diff_entries(&operations(expected), &operations(actual));
+    section(&mut out, "Operations", &operations);
+
+    let schemas = diff_entries(&schemas(expected), &schemas(actual));
+    section(&mut out, "Schemas", &schemas);
+
+    let mut other = Vec::new();
+    field_changes(
+        &without_paths_and_schemas(expected),
+        &without_paths_and_schemas(actual),
+        "",
+        &mut other,
+    );
+    if !other.is_empty() {
+        out.push_str("Other:\n");
+        for change in other {
+            let _ = writeln!(out, "  {change}");
+        }
+    }
+
+    out
+}
+
+/// An added, removed or changed operation or schema
+enum EntryChange {
+    Added(String),
+    Removed(String),
+    Changed(String, Vec<String>),
+}
+
+fn section(out: &mut String, title: &str, changes: &[EntryChange]) {
+    if changes.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(out, "{title}:");
+    for change in changes {
+        match change {
+            EntryChange::Added(name) => {
+                let _ = writeln!(out, "  + {name}");
+            }
+            EntryChange::Removed(name) => {
+                let _ = writeln!(out, "  - {name}");
+            }
+            EntryChange::Changed(name, fields) => {
+                let _ = writeln!(out, "  ~ {name}");
+                for field in fields.iter().take(MAX_FIELD_CHANGES) {
+                    let _ = writeln!(out, "      {field}");
+                }
+                if fields.len() > MAX_FIELD_CHANGES {
+                    let _ = writeln!(
+                        out,
+                        "      ... and {} more",
+                        fields.len() - MAX_FIELD_CHANGES
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn diff_entries(expected: &[(String, &Value)], actual: &[(String, &Value)]) -> Vec<EntryChange> {
+    fn find<'a>(entries: &[(String, &'a Value)], name: &str) -> Option<&'a Value> {
+        entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, value)| *value)
+    }
+
+    let mut changes = Vec::new();
+    for (name, old) in expected {
+        match find(actual, name) {
+            None => changes.push(EntryChange::Removed(name.clone())),
+            Some(new) if new != *old => {
+                let mut fields = Vec::new();
+                field_changes(old, new, "", &mut fields);
+                changes.push(EntryChange::Changed(name.clone(), fields));
+            }
+            Some(_) => {}
+        }
+    }
+    for (name, _) in actual {
+        if find(expected, name).is_none() {
+            changes.push(EntryChange::Added(name.clone()));
+        }
+    }
+    changes
+}
+
+/// `GET /users` and friends with their operation objects
+fn operations(spec: &Value) -> Vec<(String, &Value)> {
+    diff::operations(spec)
+        .into_iter()
+        .map(|(operation, value)| (operation.to_string(), value))
+        .collect()
+}
+
+fn schemas(spec: &Value) -> Vec<(String, &Value)> {
+    spec.pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .map(|schemas| {
+            schemas
+                .iter()
+                .map(|(name, schema)| (name.clone(), schema))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The spec without the operations and schemas, which are diffed on their own.
+/// Path-level fields such as shared parameters stay in.
+fn without_paths_and_schemas(spec: &Value) -> Value {
+    let mut rest = spec.clone();
+    if let Some(paths) = rest.get_mut("paths").and_then(Value::as_object_mut) {
+        for item in paths.values_mut().filter_map(Value::as_object_mut) {
+            item.retain(|key, _| !is_method(key));
+        }
+        paths.retain(|_, item| item.as_object().is_none_or(|item| !item.is_empty()));
+    }
+    if let Some(components) = rest.get_mut("components").and_then(Value::as_object_mut) {
+        components.remove("schemas");
+    }
+    rest
+}
+
+/// Leaf-level differences between two values, as `+ a/b`, `- a/b` and
+/// `a/b: old -> new` lines
+fn field_changes(old: &Value, new: &Value, prefix: &str, changes: &mut Vec<String>) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}/{key}")
+        }
+    };
+
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                match new.get(key) {
+                    Some(new_value) => field_changes(old_value, new_value, &join(key), changes),
+                    None => changes.push(format!("- {}: {}", join(key), short(old_value))),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    changes.push(format!("+ {}: {}", join(key), short(new_value)));
+                }
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items))
+            if old_items.len() == new_items.len() =>
+        {
+            for (index, (old_value, new_value)) in old_items.iter().zip(new_items).enumerate() {
+                field_changes(old_value, new_value, &join(&index.to_string()), changes);
+            }
+        }
+        _ if old != new => {
+            let field = if prefix.is_empty() { "(root)" } else { prefix };
+            changes.push(format!("{field}: {} -> {}", short(old), short(new)));
+        }
+        _ => {}
+    }
+}
+
+/// A value as compact JSON, shortened to keep diff lines readable
+fn short(value: &Value) -> String {
+    let text = value.to_string();
+    if text.chars().count() <= MAX_VALUE_LEN {
+        text
+    } else {
+        let shortened: String = text.chars().take(MAX_VALUE_LEN).collect();
+        format!("{shortened}...")
+    }
+}
@@ -0,0 +1,1003 @@
+//! Validating requests and responses against the finished spec's schemas,
+//! behind the `validation` feature.
+
+use std::fmt::{self, Write as _};
+use std::sync::Arc;
+
+use aide::openapi::OpenApi;
+use axum::body::Body;
+use axum::extract::{Query, Request, State};
+use axum::http::{header, HeaderMap, Method, StatusCode, Uri};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use jsonschema::{Draft, Registry, Validator};
+use serde_json::{json, Value};
+
+use crate::diff::is_method;
+use crate::{Error, OperationRef};
+
+/// URI the finished spec is registered under, so schemas can `$ref` into it
+const SPEC_URI: &str = "urn:rovo:openapi";
+
+/// Largest request body read for validation, matching axum's default limit
+const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Custom response for requests that fail validation
+type Responder = Arc<dyn Fn(&[Violation]) -> Response + Send + Sync>;
+
+/// Settings for validating requests against the spec, passed to
+/// [`Router::validate_requests`](crate::Router::validate_requests)
+///
+/// Requests that fail validation get an RFC 7807 `application/problem+json`
+/// response listing every violation:
+///
+/// ```json
+/// {
+///   "type": "about:blank",
+///   "title": "Request validation failed",
+///   "status": 422,
+///   "detail": "The request does not match the API's schema",
+///   "instance": "/users",
+///   "errors": [
+///     { "in": "query", "name": "limit", "pointer": "", "message": "1000 is greater than the maximum of 100" },
+///     { "in": "body", "pointer": "/email", "message": "\"bob\" is not a \"email\"" }
+///   ]
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RequestValidation {
+    status: StatusCode,
+    problem_type: String,
+    title: String,
+    body_limit: usize,
+    responder: Option<Responder>,
+}
+
+impl Default for RequestValidation {
+    fn default() -> Self {
+        Self {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            problem_type: "about:blank".to_string(),
+            title: "Request validation failed".to_string(),
+            body_limit: DEFAULT_BODY_LIMIT,
+            responder: None,
+        }
+    }
+}
+
+impl fmt::Debug for RequestValidation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestValidation")
+            .field("status", &self.status)
+            .field("problem_type", &self.problem_type)
+            .field("title", &self.title)
+            .field("body_limit", &self.body_limit)
+            .field("responder", &self.responder.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl RequestValidation {
+    /// Validate with the default problem response: `422 Unprocessable Entity`,
+    /// type `about:blank`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Status code of the problem response
+    #[must_use]
+    pub const fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// The problem's `type` URI
+    #[must_use]
+    pub fn problem_type(mut self, problem_type: impl Into<String>) -> Self {
+        self.problem_type = problem_type.into();
+        self
+    }
+
+    /// The problem's `title`
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Largest JSON body read for validation, 2 MiB by default; larger bodies
+    /// get `413 Payload Too Large`
+    #[must_use]
+    pub const fn body_limit(mut self, limit: usize) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// Build the response for an invalid request yourself instead of the
+    /// problem document
+    ///
+    /// ```
+    /// use rovo::http::StatusCode;
+    /// use rovo::response::IntoResponse;
+    /// use rovo::RequestValidation;
+    ///
+    /// let validation = RequestValidation::new().respond_with(|violations| {
+    ///     let messages: Vec<String> = violations.iter().map(ToString::to_string).collect();
+    ///     (StatusCode::BAD_REQUEST, messages.join("\n")).into_response()
+    /// });
+    /// ```
+    #[must_use]
+    pub fn respond_with(
+        mut self,
+        responder: impl Fn(&[Violation]) -> Response + Send + Sync + 'static,
+    ) -> Self {
+        self.responder = Some(Arc::new(responder));
+        self
+    }
+
+    fn respond(&self, violations: &[Violation], uri: &Uri) -> Response {
+        if let Some(responder) = &self.responder {
+            return responder(violations);
+        }
+
+        let errors: Vec<Value> = violations.iter().map(Violation::to_json).collect();
+        problem(
+            self.status,
+            &self.problem_type,
+            &self.title,
+            "The request does not match the API's schema",
+            uri,
+            Some(errors),
+        )
+    }
+}
+
+/// Where in a request a [`Violation`] was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ViolationLocation {
+    /// A path parameter
+    Path,
+    /// A query parameter
+    Query,
+    /// A header
+    Header,
+    /// The request body
+    Body,
+}
+
+impl ViolationLocation {
+    /// The location as named in `OpenAPI` parameters: `path`, `query`,
+    /// `header` or `body`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Query => "query",
+            Self::Header => "header",
+            Self::Body => "body",
+        }
+    }
+
+    fn from_parameter(location: &str) -> Option<Self> {
+        match location {
+            "path" => Some(Self::Path),
+            "query" => Some(Self::Query),
+            "header" => Some(Self::Header),
+            _ => None,
+        }
+    }
+}
+
+/// A part of a request that doesn't match its schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Where the invalid value is
+    pub location: ViolationLocation,
+    /// The parameter's name, `None` for the body
+    pub name: Option<String>,
+    /// JSON pointer to the invalid value inside the parameter or body, empty
+    /// for the value itself
+    pub pointer: String,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl Violation {
+    fn to_json(&self) -> Value {
+        let mut error = json!({
+            "in": self.location.as_str(),
+            "pointer": self.pointer,
+            "message": self.message,
+        });
+        if let Some(name) = &self.name {
+            error["name"] = name.as_str().into();
+        }
+        error
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location.as_str())?;
+        if let Some(name) = &self.name {
+            write!(f, " `{name}`")?;
+        }
+        if !self.pointer.is_empty() {
+            write!(f, " at {}", self.pointer)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Settings for checking responses against the spec, passed to
+/// [`Router::validate_responses`](crate::Router::validate_responses)
+///
+/// Every response of a documented operation must use a documented status
+/// code, one of the documented media types and, for JSON, match the
+/// documented schema. Mismatches are logged as `tracing` warnings, or panic
+/// with [`panic_on_mismatch`](Self::panic_on_mismatch).
+#[derive(Debug, Clone, Default)]
+pub struct ResponseValidation {
+    panic: bool,
+}
+
+impl ResponseValidation {
+    /// Log mismatches as `tracing` warnings
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Panic on mismatches instead of logging them, for tests
+    #[must_use]
+    pub const fn panic_on_mismatch(mut self) -> Self {
+        self.panic = true;
+        self
+    }
+
+    fn report(&self, operation: &OperationRef, status: StatusCode, mismatches: &[String]) {
+        if mismatches.is_empty() {
+            return;
+        }
+
+        let mut message = format!("response of {operation} does not match its documentation:");
+        for mismatch in mismatches {
+            let _ = write!(message, "\n  - {mismatch}");
+        }
+        assert!(!self.panic, "{message}");
+        tracing::warn!(%operation, status = status.as_u16(), "{message}");
+    }
+}
+
+/// Wrap `router` in a layer validating the requests and responses of the
+/// operations of `api`
+pub fn install<S>(
+    router: axum::Router<S>,
+    api: &OpenApi,
+    requests: Option<RequestValidation>,
+    responses: Option<ResponseValidation>,
+) -> Result<axum::Router<S>, Error>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let validator = Arc::new(SpecValidator::new(api, requests, responses)?);
+    Ok(router.layer(axum::middleware::from_fn_with_state(validator, validate)))
+}
+
+async fn validate(
+    State(validator): State<Arc<SpecValidator>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((operation, path_values)) = validator.find(request.method(), request.uri().path())
+    else {
+        return next.run(request).await;
+    };
+
+    let request = match &validator.requests {
+        Some(config) => match operation.check_request(&path_values, request, config).await {
+            Ok(request) => request,
+            Err(response) => return response,
+        },
+        None => request,
+    };
+
+    let response = next.run(request).await;
+    match &validator.responses {
+        Some(config) => operation.check_response(response, config).await,
+        None => response,
+    }
+}
+
+/// The compiled schemas of every operation in the spec
+struct SpecValidator {
+    operations: Vec<OperationValidator>,
+    requests: Option<RequestValidation>,
+    responses: Option<ResponseValidation>,
+}
+
+struct OperationValidator {
+    operation: OperationRef,
+    method: Method,
+    segments: Vec<Segment>,
+    parameters: Vec<ParameterValidator>,
+    body: Option<BodyValidator>,
+    responses: Vec<ResponseValidator>,
+}
+
+/// A segment of a path template
+enum Segment {
+    Literal(String),
+    Parameter(String),
+}
+
+struct ParameterValidator {
+    name: String,
+    location: ViolationLocation,
+    required: bool,
+    coercion: Coercion,
+    schema: Option<Validator>,
+}
+
+struct BodyValidator {
+    required: bool,
+    /// Schema of the JSON content, `None` if the body isn't JSON
+    schema: Option<Validator>,
+}
+
+/// A documented response
+struct ResponseValidator {
+    /// `200`, a range such as `2XX`, or `default`
+    status: String,
+    media_types: Vec<String>,
+    /// Schema of the JSON content, `None` if the response isn't JSON
+    schema: Option<Validator>,
+}
+
+/// How the strings of a path, query or header parameter become JSON
+enum Coercion {
+    /// One value, tried as each of these types in turn
+    Scalar(Vec<String>),
+    /// Every occurrence of the parameter, each tried as these types
+    Array(Vec<String>),
+}
+
+impl SpecValidator {
+    fn new(
+        api: &OpenApi,
+        requests: Option<RequestValidation>,
+        responses: Option<ResponseValidation>,
+    ) -> Result<Self, Error> {
+        let spec = serde_json::to_value(api).map_err(|err| Error::Serialize {
+            format: "JSON",
+            message: err.to_string(),
+        })?;
+        let registry =
+            Registry::try_new(SPEC_URI, Draft::Draft202012.create_resource(spec.clone())).map_err(
+                |err| Error::InvalidSchema {
+                    location: "the spec".to_string(),
+                    message: err.to_string(),
+                },
+            )?;
+        let compiler = Compiler {
+            spec: &spec,
+            registry,
+        };
+
+        let mut operations = Vec::new();
+        let paths = spec.get("paths").and_then(Value::as_object);
+        for (path, item) in paths.into_iter().flatten() {
+            let item_pointer = format!("/paths/{}", escape(path));
+            let Some(item) = item.as_object() else {
+                continue;
+            };
+            for (method, operation) in item.iter().filter(|(key, _)| is_method(key)) {
+                let Ok(method_value) = Method::from_bytes(method.to_uppercase().as_bytes()) else {
+                    continue;
+                };
+                let operation_pointer = format!("{item_pointer}/{method}");
+                let operation_ref = OperationRef {
+                    method: method.to_uppercase(),
+                    path: path.clone(),
+                };
+                let label = operation_ref.to_string();
+
+                let mut parameters: Vec<ParameterValidator> = Vec::new();
+                let sources = [
+                    (item.get("parameters"), format!("{item_pointer}/parameters")),
+                    (
+                        operation.get("parameters"),
+                        format!("{operation_pointer}/parameters"),
+                    ),
+                ];
+                for (list, list_pointer) in sources {
+                    let list = list.and_then(Value::as_array).map(Vec::as_slice);
+                    for (index, parameter) in list.unwrap_or_default().iter().enumerate() {
+                        let pointer = format!("{list_pointer}/{index}");
+                        if let Some(parameter) = compiler.parameter(parameter, pointer, &label)? {
+                            // The operation's own parameters override the path item's
+                            parameters.retain(|existing| {
+                                existing.name != parameter.name
+                                    || existing.location != parameter.location
+                            });
+                            parameters.push(parameter);
+                        }
+                    }
+                }
+
+                let body = match operation.get("requestBody") {
+                    Some(body) => Some(compiler.body(
+                        body,
+                        format!("{operation_pointer}/requestBody"),
+                        &label,
+                    )?),
+                    None => None,
+                };
+
+                operations.push(OperationValidator {
+                    responses: compiler.responses(operation, &operation_pointer, &label)?,
+                    operation: operation_ref,
+                    method: method_value,
+                    segments: segments(path),
+                    parameters,
+                    body,
+                });
+            }
+        }
+
+        Ok(Self {
+            operations,
+            requests,
+            responses,
+        })
+    }
+
+    /// The operation a request is for, preferring literal segments over
+    /// parameters, with the values of its path parameters
+    fn find(
+        &self,
+        method: &Method,
+        path: &str,
+    ) -> Option<(&OperationValidator, Vec<(&str, String)>)> {
+        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+        self.operations
+            .iter()
+            .filter(|operation| operation.method == *method)
+            .filter_map(|operation| {
+                if operation.segments.len() != parts.len() {
+                    return None;
+                }
+                let mut values = Vec::new();
+                for (segment, part) in operation.segments.iter().zip(&parts) {
+                    match segment {
+                        Segment::Literal(literal) if literal == part => {}
+                        Segment::Literal(_) => return None,
+                        Segment::Parameter(name) => {
+                            values.push((name.as_str(), percent_decode(part)));
+                        }
+                    }
+                }
+                Some((operation, values))
+            })
+            .min_by_key(|(_, values)| values.len())
+    }
+}
+
+impl OperationValidator {
+    /// Pass a valid request on, or answer an invalid one
+    async fn check_request(
+        &self,
+        path_values: &[(&str, String)],
+        request: Request,
+        config: &RequestValidation,
+    ) -> Result<Request, Response> {
+        let mut violations = Vec::new();
+        let query = Query::<Vec<(String, String)>>::try_from_uri(request.uri())
+            .map(|Query(query)| query)
+            .unwrap_or_default();
+        for parameter in &self.parameters {
+            let values: Vec<&str> = match parameter.location {
+                ViolationLocation::Path => path_values
+                    .iter()
+                    .filter(|(name, _)| *name == parameter.name)
+                    .map(|(_, value)| value.as_str())
+                    .collect(),
+                ViolationLocation::Query => query
+                    .iter()
+                    .filter(|(name, _)| *name == parameter.name)
+                    .map(|(_, value)| value.as_str())
+                    .collect(),
+                ViolationLocation::Header => request
+                    .headers()
+                    .get_all(parameter.name.as_str())
+                    .iter()
+                    .filter_map(|value| value.to_str().ok())
+                    .collect(),
+                ViolationLocation::Body => Vec::new(),
+            };
+            parameter.check(&values, &mut violations);
+        }
+
+        let request = match &self.body {
+            Some(body) => body.check(request, config, &mut violations).await?,
+            None => request,
+        };
+
+        if violations.is_empty() {
+            Ok(request)
+        } else {
+            Err(config.respond(&violations, request.uri()))
+        }
+    }
+
+    /// Report a response that doesn't match the documented ones, handing it
+    /// back with the body buffered
+    async fn check_response(&self, response: Response, config: &ResponseValidation) -> Response {
+        let status = response.status();
+        let Some(documented) = self.response(status) else {
+            let codes: Vec<&str> = self.responses.iter().map(|r| r.status.as_str()).collect();
+            let documented = if codes.is_empty() {
+                "none".to_string()
+            } else {
+                codes.join(", ")
+            };
+            let mismatch = format!(
+                "status {} is not documented (documented: {documented})",
+                status.as_u16()
+            );
+            config.report(&self.operation, status, &[mismatch]);
+            return response;
+        };
+
+        // Only the media type and JSON schema are checked, a response
+        // documented without content may still have a body
+        let Some(content_type) = media_type(response.headers()) else {
+            return response;
+        };
+        if documented.media_types.is_empty() {
+            return response;
+        }
+        if !documented
+            .media_types
+            .iter()
+            .any(|media_type| media_type_matches(media_type, &content_type))
+        {
+            let mismatch = format!(
+                "content type `{content_type}` is not documented for status {} (documented: {})",
+                status.as_u16(),
+                documented.media_types.join(", ")
+            );
+            config.report(&self.operation, status, &[mismatch]);
+            return response;
+        }
+        let (true, Some(schema)) = (is_json_media_type(&content_type), &documented.schema) else {
+            return response;
+        };
+
+        let (parts, body) = response.into_parts();
+        let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                let mismatch = format!("the body could not be read: {err}");
+                config.report(&self.operation, status, &[mismatch]);
+                return Response::from_parts(parts, Body::empty());
+            }
+        };
+
+        let mismatches: Vec<String> = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(instance) => schema
+                .iter_errors(&instance)
+                .map(|error| match error.instance_path().to_string() {
+                    pointer if pointer.is_empty() => format!("body: {error}"),
+                    pointer => format!("body at {pointer}: {error}"),
+                })
+                .collect(),
+            Err(err) => vec![format!("body is not valid JSON: {err}")],
+        };
+        config.report(&self.operation, status, &mismatches);
+
+        Response::from_parts(parts, Body::from(bytes))
+    }
+
+    /// The documented response for a status: the exact code, its range, or
+    /// the default response
+    fn response(&self, status: StatusCode) -> Option<&ResponseValidator> {
+        let code = status.as_str();
+        let range = format!("{}XX", &code[..1]);
+        let find = |key: &str| {
+            self.responses
+                .iter()
+                .find(|response| response.status.eq_ignore_ascii_case(key))
+        };
+        find(code)
+            .or_else(|| find(&range))
+            .or_else(|| find("default"))
+    }
+}
+
+impl BodyValidator {
+    /// Validate a JSON body, handing the request back with the body buffered
+    async fn check(
+        &self,
+        request: Request,
+        config: &RequestValidation,
+        violations: &mut Vec<Violation>,
+    ) -> Result<Request, Response> {
+        let content_type = media_type(request.headers());
+        // Other media types are left to the handler's extractor, only a
+        // missing body is caught for them
+        let is_json = content_type.as_deref().is_some_and(is_json_media_type);
+        if content_type.is_some() && !is_json {
+            return Ok(request);
+        }
+
+        let (parts, request_body) = request.into_parts();
+        let Ok(bytes) = axum::body::to_bytes(request_body, config.body_limit).await else {
+            return Err(problem(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "about:blank",
+                "Payload Too Large",
+                &format!("The request body exceeds {} bytes", config.body_limit),
+                &parts.uri,
+                None,
+            ));
+        };
+
+        if bytes.is_empty() {
+            if self.required {
+                violations.push(Violation {
+                    location: ViolationLocation::Body,
+                    name: None,
+                    pointer: String::new(),
+                    message: "the request body is required".to_string(),
+                });
+            }
+        } else if let (true, Some(schema)) = (is_json, &self.schema) {
+            // Malformed JSON is left to the handler's `Json` extractor
+            if let Ok(instance) = serde_json::from_slice::<Value>(&bytes) {
+                for error in schema.iter_errors(&instance) {
+                    violations.push(Violation {
+                        location: ViolationLocation::Body,
+                        name: None,
+                        pointer: error.instance_path().to_string(),
+                        message: error.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(Request::from_parts(parts, Body::from(bytes)))
+    }
+}
+
+impl ParameterValidator {
+    fn check(&self, values: &[&str], violations: &mut Vec<Violation>) {
+        let violation = |pointer: String, message: String| Violation {
+            location: self.location,
+            name: Some(self.name.clone()),
+            pointer,
+            message,
+        };
+
+        if values.is_empty() {
+            if self.required {
+                let message = format!("required {} parameter is missing", self.location.as_str());
+                violations.push(violation(String::new(), message));
+            }
+            return;
+        }
+
+        let Some(schema) = &self.schema else {
+            return;
+        };
+        let instance = match &self.coercion {
+            Coercion::Scalar(types) => coerce(values[0], types),
+            Coercion::Array(types) => values.iter().map(|value| coerce(value, types)).collect(),
+        };
+        for error in schema.iter_errors(&instance) {
+            violations.push(violation(
+                error.instance_path().to_string(),
+                error.to_string(),
+            ));
+        }
+    }
+}
+
+/// Compiles the schemas of a spec, which they may `$ref` into
+struct Compiler<'a> {
+    spec: &'a Value,
+    registry: Registry,
+}
+
+impl Compiler<'_> {
+    fn parameter(
+        &self,
+        parameter: &Value,
+        pointer: String,
+        operation: &str,
+    ) -> Result<Option<ParameterValidator>, Error> {
+        let (parameter, pointer) = self.resolve(parameter, pointer);
+        let (Some(name), Some(location)) = (
+            parameter.get("name").and_then(Value::as_str),
+            parameter
+                .get("in")
+                .and_then(Value::as_str)
+                .and_then(ViolationLocation::from_parameter),
+        ) else {
+            // Cookies aren't validated
+            return Ok(None);
+        };
+
+        let (schema, coercion) = match parameter.get("schema") {
+            Some(schema) => {
+                let schema_pointer = format!("{pointer}/schema");
+                let (resolved, _) = self.resolve(schema, schema_pointer.clone());
+                let coercion = if types(resolved).iter().any(|ty| ty == "array") {
+                    let items = resolved
+                        .get("items")
+                        .map(|items| self.resolve(items, String::new()).0);
+                    Coercion::Array(items.map(types).unwrap_or_default())
+                } else {
+                    Coercion::Scalar(types(resolved))
+                };
+                let label = format!("{operation} {} parameter `{name}`", location.as_str());
+                (Some(self.compile(&schema_pointer, &label)?), coercion)
+            }
+            None => (None, Coercion::Scalar(Vec::new())),
+        };
+
+        Ok(Some(ParameterValidator {
+            name: name.to_string(),
+            location,
+            required: parameter.get("required") == Some(&Value::Bool(true)),
+            coercion,
+            schema,
+        }))
+    }
+
+    fn body(&self, body: &Value, pointer: String, operation: &str) -> Result<BodyValidator, Error> {
+        let (body, pointer) = self.resolve(body, pointer);
+        let json_content = body
+            .get("content")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .find(|(media_type, content)| {
+                is_json_media_type(media_type) && content.get("schema").is_some()
+            });
+
+        let schema = match json_content {
+            Some((media_type, _)) => {
+                let schema_pointer = format!("{pointer}/content/{}/schema", escape(media_type));
+                Some(self.compile(&schema_pointer, &format!("{operation} request body"))?)
+            }
+            None => None,
+        };
+
+        Ok(BodyValidator {
+            required: body.get("required") == Some(&Value::Bool(true)),
+            schema,
+        })
+    }
+
+    fn responses(
+        &self,
+        operation: &Value,
+        pointer: &str,
+        label: &str,
+    ) -> Result<Vec<ResponseValidator>, Error> {
+        let mut validators = Vec::new();
+        let responses = operation.get("responses").and_then(Value::as_object);
+        for (status, response) in responses.into_iter().flatten() {
+            let (response, pointer) =
+                self.resolve(response, format!("{pointer}/responses/{}", escape(status)));
+            let content = response.get("content").and_then(Value::as_object);
+            let json_content = content.into_iter().flatten().find(|(media_type, content)| {
+                is_json_media_type(media_type) && content.get("schema").is_some()
+            });
+
+            let schema = match json_content {
+                Some((media_type, _)) => {
+                    let schema_pointer = format!("{pointer}/content/{}/schema", escape(media_type));
+                    let label = format!("{label} response {status}");
+                    Some(self.compile(&schema_pointer, &label)?)
+                }
+                None => None,
+            };
+
+            validators.push(ResponseValidator {
+                status: status.clone(),
+                media_types: content
+                    .into_iter()
+                    .flatten()
+                    .map(|(m, _)| m.clone())
+                    .collect(),
+                schema,
+            });
+        }
+        Ok(validators)
+    }
+
+    /// Compile the schema at a JSON pointer into the spec
+    fn compile(&self, pointer: &str, label: &str) -> Result<Validator, Error> {
+        jsonschema::options()
+            .with_draft(Draft::Draft202012)
+            .should_validate_formats(true)
+            .with_registry(self.registry.clone())
+            .build(&json!({ "$ref": format!("{SPEC_URI}#{}", fragment(pointer)) }))
+            .map_err(|err| Error::InvalidSchema {
+                location: label.to_string(),
+                message: err.to_string(),
+            })
+    }
+
+    /// Follow local `$ref`s, keeping track of the pointer to the target
+    fn resolve<'v>(&'v self, mut value: &'v Value, mut pointer: String) -> (&'v Value, String) {
+        while let Some(target) = value
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+        {
+            let Some(resolved) = self.spec.pointer(target) else {
+                break;
+            };
+            if std::ptr::eq(resolved, value) {
+                break;
+            }
+            value = resolved;
+            pointer = target.to_string();
+        }
+        (value, pointer)
+    }
+}
+
+/// Turn a parameter string into the JSON value its schema expects, falling
+/// back to a string the schema can reject
+fn coerce(value: &str, types: &[String]) -> Value {
+    for ty in types {
+        let coerced = match ty.as_str() {
+            "integer" => value
+                .parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| value.parse::<u64>().map(Value::from))
+                .ok(),
+            "number" => value
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+            "boolean" => value.parse::<bool>().ok().map(Value::Bool),
+            "string" => Some(Value::String(value.to_string())),
+            _ => None,
+        };
+        if let Some(coerced) = coerced {
+            return coerced;
+        }
+    }
+    Value::String(value.to_string())
+}
+
+fn types(schema: &Value) -> Vec<String> {
+    match schema.get("type") {
+        Some(Value::String(ty)) => vec![ty.clone()],
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .map(ToString::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn segments(path: &str) -> Vec<Segment> {
+    path.trim_start_matches('/')
+        .split('/')
+        .map(|segment| {
+            segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+                .map_or_else(
+                    || Segment::Literal(segment.to_string()),
+                    |name| Segment::Parameter(name.to_string()),
+                )
+        })
+        .collect()
+}
+
+/// The lower-cased media type of a request or response, without parameters
+fn media_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+        })
+}
+
+/// Whether a documented media type, possibly `type/*` or `*/*`, covers `actual`
+fn media_type_matches(documented: &str, actual: &str) -> bool {
+    let documented = documented.to_ascii_lowercase();
+    documented == "*/*"
+        || documented == actual
+        || documented
+            .strip_suffix("/*")
+            .is_some_and(|prefix| actual.split('/').next() == Some(prefix))
+}
+
+fn is_json_media_type(media_type: &str) -> bool {
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// Escape a key for use in a JSON pointer
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Percent-encode a JSON pointer for use as a URI fragment
+fn fragment(pointer: &str) -> String {
+    let mut encoded = String::with_capacity(pointer.len());
+    for byte in pointer.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/?".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+/// Decode `%XX` escapes in a path segment, leaving invalid ones as they are
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| segment.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// An RFC 7807 problem document
+fn problem(
+    status: StatusCode,
+    problem_type: &str,
+    title: &str,
+    detail: &str,
+    uri: &Uri,
+    errors: Option<Vec<Value>>,
+) -> Response {
+    let mut body = json!({
+        "type": problem_type,
+        "title": title,
+        "status": status.as_u16(),
+        "detail": detail,
+        "instance": uri.path(),
+    });
+    if let Some(errors) = errors {
+        body["errors"] = Value::Array(errors);
+    }
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/problem+json")],
+        body.to_string(),
+    )
+        .into_response()
+}
@@ -0,0 +1,88 @@
+//! Helpers shared by the integration tests
+// Each test crate only uses some of these
+#![allow(dead_code)]
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use rovo::aide::openapi::{OpenApi, Operation, PathItem, ReferenceOr, StatusCode as OasStatusCode};
+use rovo::Router;
+
+/// An empty spec with a title, as `finish_api` expects
+pub fn api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
+
+/// The spec generated for `router`
+pub fn spec_for<S>(router: Router<S>) -> OpenApi
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let mut api = api();
+    let _ = router.finish_api(&mut api);
+    api
+}
+
+/// The operation at `path`, picked out of its path item by `method`
+pub fn op<'a>(
+    spec: &'a OpenApi,
+    path: &str,
+    method: impl Fn(&'a PathItem) -> Option<&'a Operation>,
+) -> &'a Operation {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    method(paths[path].as_item().unwrap()).unwrap()
+}
+
+/// The description of the response documented for `status`
+pub fn response_description(op: &Operation, status: u16) -> Option<&str> {
+    match op
+        .responses
+        .as_ref()?
+        .responses
+        .get(&OasStatusCode::Code(status))?
+    {
+        ReferenceOr::Item(response) => Some(response.description.as_str()),
+        ReferenceOr::Reference { .. } => None,
+    }
+}
+
+/// Log output captured from a `tracing` subscriber
+#[derive(Clone, Default)]
+pub struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Logs {
+    /// Capture the logs of the current thread until the guard is dropped
+    pub fn capture(&self) -> tracing::subscriber::DefaultGuard {
+        tracing::subscriber::set_default(
+            tracing_subscriber::fmt()
+                .with_writer(self.clone())
+                .with_ansi(false)
+                .finish(),
+        )
+    }
+
+    pub fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for Logs {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
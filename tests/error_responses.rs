@@ -0,0 +1,115 @@
+//! Tests for error responses merged from `ErrorResponses` implementations
+
+mod common;
+
+use common::{op, response_description, spec_for};
+use rovo::http::StatusCode;
+use rovo::response::{IntoResponse, Json, Response};
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, ErrorResponses, Router};
+use serde::Serialize;
+
+#[derive(Serialize, JsonSchema)]
+struct User {
+    name: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ApiError {
+    message: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(self)).into_response()
+    }
+}
+
+impl rovo::aide::OperationOutput for ApiError {
+    type Inner = Self;
+}
+
+impl ErrorResponses for ApiError {
+    type Response = Json<Self>;
+
+    const RESPONSES: &'static [(u16, &'static str)] = &[
+        (400, "Invalid request"),
+        (401, "Missing credentials"),
+        (500, "Internal server error"),
+    ];
+}
+
+/// Get the current user.
+#[rovo]
+async fn get_me() -> Result<Json<User>, ApiError> {
+    Ok(Json(User {
+        name: "me".to_string(),
+    }))
+}
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 401: Json<ApiError> - Not logged in
+#[rovo]
+async fn get_user() -> Result<Json<User>, ApiError> {
+    Ok(Json(User {
+        name: "user".to_string(),
+    }))
+}
+
+/// Get a user, failing with a bare status code.
+#[rovo]
+async fn get_plain() -> Result<Json<User>, StatusCode> {
+    Err(StatusCode::NOT_FOUND)
+}
+
+struct Users;
+
+#[rovo]
+impl Users {
+    /// List users.
+    #[rovo]
+    async fn list() -> Result<Json<Vec<User>>, ApiError> {
+        Ok(Json(vec![]))
+    }
+}
+
+#[test]
+fn error_responses_are_merged() {
+    let spec = spec_for(Router::new().route("/me", get(get_me)));
+    let op = op(&spec, "/me", |item| item.get.as_ref());
+
+    assert_eq!(response_description(op, 200), Some("Successful response"));
+    assert_eq!(response_description(op, 400), Some("Invalid request"));
+    assert_eq!(response_description(op, 401), Some("Missing credentials"));
+    assert_eq!(response_description(op, 500), Some("Internal server error"));
+}
+
+#[test]
+fn documented_responses_override_error_responses() {
+    let spec = spec_for(Router::new().route("/user", get(get_user)));
+    let op = op(&spec, "/user", |item| item.get.as_ref());
+
+    assert_eq!(response_description(op, 401), Some("Not logged in"));
+    assert_eq!(response_description(op, 400), Some("Invalid request"));
+}
+
+#[test]
+fn error_types_without_error_responses_are_ignored() {
+    let spec = spec_for(Router::new().route("/plain", get(get_plain)));
+    let op = op(&spec, "/plain", |item| item.get.as_ref());
+
+    assert_eq!(op.responses.as_ref().unwrap().responses.len(), 1);
+}
+
+#[test]
+fn error_responses_apply_to_impl_block_handlers() {
+    let spec = spec_for(Router::new().route("/users", get(Users::list)));
+    let op = op(&spec, "/users", |item| item.get.as_ref());
+
+    assert_eq!(response_description(op, 500), Some("Internal server error"));
+}
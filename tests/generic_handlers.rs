@@ -0,0 +1,141 @@
+//! Tests for generic `#[rovo]` handlers and handlers with `where` clauses
+
+mod common;
+
+use common::{op, spec_for};
+use rovo::aide::axum::IntoApiResponse;
+use rovo::extract::{Path, State};
+use rovo::response::Json;
+use rovo::routing::{get, post};
+use rovo::{rovo, Router};
+
+trait Repo: Clone + Send + Sync + 'static {
+    fn names(&self) -> Vec<String>;
+}
+
+#[derive(Clone)]
+struct PgRepo;
+
+impl Repo for PgRepo {
+    fn names(&self) -> Vec<String> {
+        vec!["pg".to_string()]
+    }
+}
+
+#[derive(Clone)]
+struct AppState<R: Repo> {
+    repo: R,
+}
+
+/// List names.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - All names
+#[rovo]
+async fn list<R: Repo>(State(state): State<AppState<R>>) -> impl IntoApiResponse {
+    Json(state.repo.names())
+}
+
+/// Get a name.
+///
+/// # Path Parameters
+///
+/// index: Position of the name
+///
+/// # Responses
+///
+/// 200: Json<String> - The name
+#[rovo(id = "getName")]
+async fn get_name<R>(
+    State(state): State<AppState<R>>,
+    Path(index): Path<u32>,
+) -> impl IntoApiResponse
+where
+    R: Repo,
+{
+    Json(
+        state
+            .repo
+            .names()
+            .get(index as usize)
+            .cloned()
+            .unwrap_or_default(),
+    )
+}
+
+struct Names;
+
+#[rovo]
+impl Names {
+    /// Create a name.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<String>> - Names after creation
+    #[rovo]
+    async fn create<R: Repo>(State(state): State<AppState<R>>) -> impl IntoApiResponse {
+        Json(state.repo.names())
+    }
+}
+
+#[test]
+fn generic_handlers_are_documented() {
+    let spec = spec_for(
+        Router::new()
+            .route("/names", get(list::<PgRepo>).post(Names::create::<PgRepo>))
+            .route("/names/{index}", get(get_name::<PgRepo>)),
+    );
+
+    let list_op = op(&spec, "/names", |item| item.get.as_ref());
+    assert_eq!(list_op.summary.as_deref(), Some("List names."));
+    assert_eq!(list_op.operation_id.as_deref(), Some("list"));
+
+    let create_op = op(&spec, "/names", |item| item.post.as_ref());
+    assert_eq!(create_op.operation_id.as_deref(), Some("create"));
+
+    let get_op = op(&spec, "/names/{index}", |item| item.get.as_ref());
+    assert_eq!(get_op.operation_id.as_deref(), Some("getName"));
+    assert_eq!(get_op.parameters.len(), 1);
+}
+
+#[test]
+fn generics_are_inferred_from_router_state() {
+    let spec =
+        spec_for(Router::<AppState<PgRepo>>::new().route("/names", get(list).post(Names::create)));
+
+    assert!(op(&spec, "/names", |item| item.get.as_ref())
+        .operation_id
+        .is_some());
+}
+
+#[tokio::test]
+async fn generic_handlers_are_served() {
+    use tower::ServiceExt;
+
+    let app = Router::new()
+        .route("/names", get(list::<PgRepo>))
+        .with_state(AppState { repo: PgRepo })
+        .finish();
+
+    let response = app
+        .oneshot(
+            axum::http::Request::builder()
+                .uri("/names")
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json, serde_json::json!(["pg"]));
+}
+
+#[allow(dead_code)]
+fn post_route_accepts_generic_handlers() -> rovo::ApiMethodRouter<AppState<PgRepo>> {
+    post(list::<PgRepo>)
+}
@@ -0,0 +1,125 @@
+//! Tests for the `# Headers` doc section
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{OpenApi, Parameter, ParameterData, ParameterSchemaOrContent};
+use rovo::http::HeaderMap;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Router};
+use uuid::Uuid;
+
+/// Get the current tenant.
+///
+/// # Headers
+///
+/// X-Tenant-Id: Uuid (required) - Tenant the request acts on
+/// X-Request-Id: String - Correlation ID echoed in logs
+///
+/// # Responses
+///
+/// 200: Json<String> - The tenant
+#[rovo]
+async fn get_tenant(headers: HeaderMap) -> Json<String> {
+    Json(format!("{:?}", headers.get("x-tenant-id")))
+}
+
+#[test]
+fn headers_read_from_header_map_become_header_parameters() {
+    let spec = spec_for(Router::new().route("/tenant", get(get_tenant)));
+
+    let tenant = header_param(&spec, "/tenant", "X-Tenant-Id");
+    assert_eq!(
+        tenant.description.as_deref(),
+        Some("Tenant the request acts on")
+    );
+    assert!(tenant.required);
+    assert_eq!(schema_format(tenant), Some("uuid"));
+
+    let request_id = header_param(&spec, "/tenant", "X-Request-Id");
+    assert_eq!(
+        request_id.description.as_deref(),
+        Some("Correlation ID echoed in logs")
+    );
+    assert!(!request_id.required);
+}
+
+#[cfg(feature = "headers")]
+mod typed_header {
+    use super::*;
+    use rovo::headers::UserAgent;
+    use rovo::TypedHeader;
+
+    /// Echo the user agent.
+    ///
+    /// # Headers
+    ///
+    /// User-Agent: String - The client making the request
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - The user agent
+    #[rovo]
+    async fn echo_user_agent(TypedHeader(agent): TypedHeader<UserAgent>) -> Json<String> {
+        Json(agent.to_string())
+    }
+
+    #[test]
+    fn typed_header_is_merged_with_documentation() {
+        let spec = spec_for(Router::new().route("/ua", get(echo_user_agent)));
+
+        let op = spec.paths.as_ref().unwrap().paths["/ua"]
+            .as_item()
+            .unwrap()
+            .get
+            .as_ref()
+            .unwrap();
+        let header_count = op
+            .parameters
+            .iter()
+            .filter(|p| matches!(p.as_item(), Some(Parameter::Header { .. })))
+            .count();
+        assert_eq!(
+            header_count, 1,
+            "Documented header should not be duplicated"
+        );
+
+        let agent = header_param(&spec, "/ua", "user-agent");
+        assert_eq!(
+            agent.description.as_deref(),
+            Some("The client making the request")
+        );
+        assert!(agent.required, "TypedHeader<T> is required");
+    }
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn header_param<'a>(spec: &'a OpenApi, path: &str, name: &str) -> &'a ParameterData {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = paths[path].as_item().unwrap().get.as_ref().unwrap();
+
+    op.parameters
+        .iter()
+        .find_map(|p| match p.as_item() {
+            Some(Parameter::Header { parameter_data, .. })
+                if parameter_data.name.eq_ignore_ascii_case(name) =>
+            {
+                Some(parameter_data)
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Missing header parameter '{name}'"))
+}
+
+fn schema_format(param: &ParameterData) -> Option<&str> {
+    match &param.format {
+        ParameterSchemaOrContent::Schema(schema) => {
+            schema.json_schema.get("format").and_then(|t| t.as_str())
+        }
+        ParameterSchemaOrContent::Content(_) => None,
+    }
+}
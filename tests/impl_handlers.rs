@@ -0,0 +1,142 @@
+//! Tests for `#[rovo]` on associated functions inside `impl` blocks
+
+mod common;
+
+use common::{op, spec_for};
+use rovo::aide::axum::IntoApiResponse;
+use rovo::extract::{Path, State};
+use rovo::http::StatusCode;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::Serialize;
+
+#[derive(Clone, Default)]
+struct AppState {
+    greeting: &'static str,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+struct Users;
+
+#[rovo]
+impl Users {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - All users
+    #[rovo(tag = "users")]
+    pub async fn list(State(state): State<AppState>) -> impl IntoApiResponse {
+        Json(vec![Self::user(1, state.greeting)])
+    }
+
+    /// Get a user by ID.
+    ///
+    /// # Path Parameters
+    ///
+    /// id: The user's ID
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - User found
+    /// 404: () - User not found
+    #[rovo]
+    async fn get(State(state): State<AppState>, Path(id): Path<u64>) -> impl IntoApiResponse {
+        Json(Self::user(id, state.greeting))
+    }
+
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 201: () - User created
+    #[rovo]
+    async fn create(State(_state): State<AppState>) -> StatusCode {
+        StatusCode::CREATED
+    }
+
+    fn user(id: u64, name: &str) -> User {
+        User {
+            id,
+            name: name.to_string(),
+        }
+    }
+}
+
+struct Posts;
+
+#[rovo]
+impl Posts {
+    /// List posts.
+    ///
+    /// # Responses
+    ///
+    /// 200: () - All posts
+    #[rovo(id = "listPosts")]
+    async fn list(State(_state): State<AppState>) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[test]
+fn associated_handlers_are_documented() {
+    let spec = spec_for(
+        Router::new()
+            .route("/users", get(Users::list).post(Users::create))
+            .route("/users/{id}", get(Users::get))
+            .route("/posts", get(Posts::list)),
+    );
+
+    let list = op(&spec, "/users", |item| item.get.as_ref());
+    assert_eq!(list.summary.as_deref(), Some("List users."));
+    assert_eq!(list.operation_id.as_deref(), Some("list"));
+    assert_eq!(list.tags, vec!["users"]);
+
+    let create = op(&spec, "/users", |item| item.post.as_ref());
+    assert!(create
+        .responses
+        .as_ref()
+        .unwrap()
+        .responses
+        .contains_key(&rovo::aide::openapi::StatusCode::Code(201)));
+
+    let get_user = op(&spec, "/users/{id}", |item| item.get.as_ref());
+    assert_eq!(get_user.parameters.len(), 1);
+
+    let posts = op(&spec, "/posts", |item| item.get.as_ref());
+    assert_eq!(posts.operation_id.as_deref(), Some("listPosts"));
+}
+
+#[tokio::test]
+async fn associated_handlers_are_served() {
+    use tower::ServiceExt;
+
+    let app = Router::new()
+        .route("/users/{id}", get(Users::get))
+        .with_state(AppState { greeting: "hello" })
+        .finish();
+
+    let response = app
+        .oneshot(
+            axum::http::Request::builder()
+                .uri("/users/7")
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json, serde_json::json!({ "id": 7, "name": "hello" }));
+}
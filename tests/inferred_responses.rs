@@ -0,0 +1,112 @@
+//! Tests for responses inferred from the return type when `# Responses` is absent
+
+mod common;
+
+use common::{op, response_description, spec_for};
+use rovo::http::StatusCode;
+use rovo::response::Json;
+use rovo::routing::{delete, get, post};
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::Serialize;
+
+/// A registered user of the service
+#[derive(Serialize, JsonSchema)]
+struct User {
+    name: String,
+}
+
+/// Get the current user.
+#[rovo]
+async fn get_me() -> Json<User> {
+    Json(User {
+        name: "me".to_string(),
+    })
+}
+
+/// List users.
+#[rovo]
+async fn list_users() -> Result<Json<Vec<User>>, StatusCode> {
+    Ok(Json(vec![]))
+}
+
+/// Create a user.
+#[rovo]
+async fn create_user() -> (StatusCode, Json<User>) {
+    if false {
+        return (
+            StatusCode::CONFLICT,
+            Json(User {
+                name: String::new(),
+            }),
+        );
+    }
+    (
+        StatusCode::CREATED,
+        Json(User {
+            name: "new".to_string(),
+        }),
+    )
+}
+
+/// Delete a user.
+#[rovo]
+async fn delete_user() {}
+
+/// Get a user, with documented responses.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+#[rovo]
+async fn get_user() -> Json<User> {
+    Json(User {
+        name: "user".to_string(),
+    })
+}
+
+#[test]
+fn infers_json_response_with_type_description() {
+    let spec = spec_for(Router::new().route("/me", get(get_me)));
+    let op = op(&spec, "/me", |item| item.get.as_ref());
+
+    assert_eq!(
+        response_description(op, 200),
+        Some("A registered user of the service")
+    );
+}
+
+#[test]
+fn infers_ok_type_of_result() {
+    let spec = spec_for(Router::new().route("/users", get(list_users)));
+    let op = op(&spec, "/users", |item| item.get.as_ref());
+
+    assert_eq!(response_description(op, 200), Some("Successful response"));
+}
+
+#[test]
+fn infers_status_code_from_body() {
+    let spec = spec_for(Router::new().route("/users", post(create_user)));
+    let op = op(&spec, "/users", |item| item.post.as_ref());
+
+    assert_eq!(
+        response_description(op, 201),
+        Some("A registered user of the service")
+    );
+}
+
+#[test]
+fn infers_no_content_for_unit() {
+    let spec = spec_for(Router::new().route("/users", delete(delete_user)));
+    let op = op(&spec, "/users", |item| item.delete.as_ref());
+
+    assert_eq!(response_description(op, 204), Some("No content"));
+}
+
+#[test]
+fn documented_responses_take_precedence() {
+    let spec = spec_for(Router::new().route("/user", get(get_user)));
+    let op = op(&spec, "/user", |item| item.get.as_ref());
+
+    assert_eq!(response_description(op, 200), Some("The user"));
+}
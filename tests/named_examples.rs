@@ -0,0 +1,82 @@
+//! Tests for named examples in the `# Examples` doc section
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{MediaType, OpenApi, ReferenceOr, StatusCode};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::Serialize;
+
+#[derive(Serialize, JsonSchema, Default)]
+struct Todo {
+    id: u64,
+    title: String,
+}
+
+/// List todos.
+///
+/// # Responses
+///
+/// 200: Json<Vec<Todo>> - The todos
+/// 404: Json<String> - Not found
+///
+/// # Examples
+///
+/// 200 "empty list": vec![]
+/// 200 "one item": vec![Todo {
+///     id: 1,
+///     title: "Buy milk".into(),
+/// }]
+/// 404: "no todos".to_string()
+#[rovo]
+async fn list_todos() -> Json<Vec<Todo>> {
+    Json(vec![Todo::default()])
+}
+
+#[test]
+fn named_examples_fill_the_examples_map() {
+    let spec = spec_for(Router::new().route("/todos", get(list_todos)));
+    let media = json_media(&spec, 200);
+
+    assert!(media.example.is_none());
+    assert_eq!(media.examples.len(), 2);
+
+    let names: Vec<_> = media.examples.keys().map(String::as_str).collect();
+    assert_eq!(names, ["empty list", "one item"]);
+
+    let Some(ReferenceOr::Item(one_item)) = media.examples.get("one item") else {
+        panic!("Missing 'one item' example");
+    };
+    assert_eq!(one_item.summary.as_deref(), Some("one item"));
+    assert_eq!(
+        one_item.value,
+        Some(serde_json::json!([{ "id": 1, "title": "Buy milk" }]))
+    );
+}
+
+#[test]
+fn unnamed_example_still_sets_example() {
+    let spec = spec_for(Router::new().route("/todos", get(list_todos)));
+    let media = json_media(&spec, 404);
+
+    assert_eq!(media.example, Some(serde_json::json!("no todos")));
+    assert!(media.examples.is_empty());
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn json_media(spec: &OpenApi, status: u16) -> &MediaType {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = paths["/todos"].as_item().unwrap().get.as_ref().unwrap();
+    let responses = op.responses.as_ref().unwrap();
+
+    match responses.responses.get(&StatusCode::Code(status)) {
+        Some(ReferenceOr::Item(response)) => &response.content["application/json"],
+        _ => panic!("Missing response {status}"),
+    }
+}
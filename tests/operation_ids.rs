@@ -0,0 +1,265 @@
+//! Tests for operation ID styles, duplicate detection and disambiguation
+
+mod common;
+
+use common::{api, spec_for, Logs};
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::{OpenApi, Operation};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Error, OperationIdPrefix, Router};
+
+mod users {
+    use rovo::aide::axum::IntoApiResponse;
+    use rovo::response::Json;
+    use rovo::rovo;
+
+    /// Get a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: () - The user
+    ///
+    /// # Metadata
+    ///
+    /// @tag users
+    #[rovo]
+    pub async fn get() -> impl IntoApiResponse {
+        Json(())
+    }
+
+    /// Delete a user.
+    ///
+    /// # Responses
+    ///
+    /// 204: () - Deleted
+    #[rovo(id_style = "module")]
+    pub async fn delete() -> impl IntoApiResponse {
+        Json(())
+    }
+}
+
+mod orders {
+    use rovo::aide::axum::IntoApiResponse;
+    use rovo::response::Json;
+    use rovo::rovo;
+
+    /// Get an order.
+    ///
+    /// # Responses
+    ///
+    /// 200: () - The order
+    ///
+    /// # Metadata
+    ///
+    /// @tag orders
+    #[rovo]
+    pub async fn get() -> impl IntoApiResponse {
+        Json(())
+    }
+}
+
+/// Health check.
+///
+/// # Responses
+///
+/// 200: () - Healthy
+#[rovo]
+async fn health() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// List user accounts.
+///
+/// # Responses
+///
+/// 200: () - The accounts
+#[rovo(id_style = "camel")]
+async fn list_user_accounts() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Purge the cache.
+///
+/// # Responses
+///
+/// 204: () - Purged
+#[rovo(id_style = "tag", tag = "Admin Tools")]
+async fn purge_cache() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Export everything.
+///
+/// # Responses
+///
+/// 200: () - The export
+///
+/// # Metadata
+///
+/// @id export_all
+#[rovo(id_style = "camel")]
+async fn export_everything() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Brew coffee.
+///
+/// # Responses
+///
+/// 200: () - Brewed
+#[rovo(id_style = "tag", tag = "Café")]
+async fn brew() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Pour coffee.
+///
+/// # Responses
+///
+/// 200: () - Poured
+#[rovo(tag = "Café")]
+async fn pour() -> impl IntoApiResponse {
+    Json(())
+}
+
+#[test]
+fn try_finish_reports_duplicates_with_their_operations() {
+    let err = router().with_oas(api()).try_finish().unwrap_err();
+
+    assert!(matches!(err, Error::DuplicateOperationIds(_)));
+    assert!(
+        err.to_string()
+            .contains("`get` is used by GET /users, GET /orders"),
+        "{err}"
+    );
+}
+
+#[test]
+fn to_openapi_reports_duplicates() {
+    let err = router().to_openapi().unwrap_err();
+
+    assert!(matches!(err, Error::DuplicateOperationIds(_)));
+}
+
+#[test]
+fn finish_allows_one_handler_on_several_paths() {
+    let _ = Router::<()>::new()
+        .route("/health", get(health))
+        .route("/healthz", get(health))
+        .with_oas(api())
+        .finish();
+}
+
+#[test]
+fn finish_logs_duplicates() {
+    let logs = Logs::default();
+    let _guard = logs.capture();
+
+    let _ = router().with_oas(api()).finish();
+
+    let logs = logs.contents();
+    assert!(logs.contains("WARN"), "{logs}");
+    assert!(
+        logs.contains("`get` is used by GET /users, GET /orders"),
+        "{logs}"
+    );
+}
+
+#[test]
+fn module_path_prefix_disambiguates() {
+    let spec = spec_for(router().disambiguate_operation_ids(OperationIdPrefix::ModulePath));
+
+    assert_eq!(operation_id(&spec, "/users"), "users_get");
+    assert_eq!(operation_id(&spec, "/orders"), "orders_get");
+    // Unique IDs are left alone
+    assert_eq!(operation_id(&spec, "/health"), "health");
+}
+
+#[test]
+fn first_tag_prefix_disambiguates() {
+    let spec = spec_for(router().disambiguate_operation_ids(OperationIdPrefix::FirstTag));
+
+    assert_eq!(operation_id(&spec, "/users"), "users_get");
+    assert_eq!(operation_id(&spec, "/orders"), "orders_get");
+    assert_eq!(operation_id(&spec, "/health"), "health");
+}
+
+#[test]
+fn disambiguation_set_on_a_nested_router_applies() {
+    let spec = spec_for(Router::new().nest(
+        "/api",
+        router().disambiguate_operation_ids(OperationIdPrefix::ModulePath),
+    ));
+
+    assert_eq!(operation_id(&spec, "/api/users"), "users_get");
+}
+
+#[test]
+fn module_paths_are_not_left_in_the_spec() {
+    let spec = spec_for(router().disambiguate_operation_ids(OperationIdPrefix::ModulePath));
+    let json = serde_json::to_string(&spec).unwrap();
+
+    assert!(!json.contains("x-rovo-module-path"));
+}
+
+#[test]
+fn id_styles() {
+    let spec = spec_for(
+        Router::new()
+            .route("/accounts", get(list_user_accounts))
+            .route("/users", rovo::routing::delete(users::delete))
+            .route("/cache", rovo::routing::delete(purge_cache))
+            .route("/export", get(export_everything)),
+    );
+
+    assert_eq!(operation_id(&spec, "/accounts"), "listUserAccounts");
+    assert_eq!(
+        delete_operation_id(&spec, "/users"),
+        "users_delete",
+        "module style drops the crate name"
+    );
+    assert_eq!(
+        delete_operation_id(&spec, "/cache"),
+        "Admin_Tools_purge_cache"
+    );
+    // An explicit ID wins over the style
+    assert_eq!(operation_id(&spec, "/export"), "export_all");
+}
+
+#[test]
+fn tag_style_and_tag_prefix_agree_on_non_ascii_tags() {
+    let spec = spec_for(
+        Router::new()
+            .route("/brew", get(brew))
+            .route("/pour", get(pour))
+            .route("/pour-again", get(pour))
+            .disambiguate_operation_ids(OperationIdPrefix::FirstTag),
+    );
+
+    assert_eq!(operation_id(&spec, "/brew"), "Caf__brew");
+    assert_eq!(operation_id(&spec, "/pour"), "Caf__pour");
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn router() -> Router<()> {
+    Router::new()
+        .route("/users", get(users::get))
+        .route("/orders", get(orders::get))
+        .route("/health", get(health))
+}
+
+fn operation_id<'a>(spec: &'a OpenApi, path: &str) -> &'a str {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op: &Operation = paths[path].as_item().unwrap().get.as_ref().unwrap();
+    op.operation_id.as_deref().unwrap()
+}
+
+fn delete_operation_id<'a>(spec: &'a OpenApi, path: &str) -> &'a str {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op: &Operation = paths[path].as_item().unwrap().delete.as_ref().unwrap();
+    op.operation_id.as_deref().unwrap()
+}
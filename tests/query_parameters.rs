@@ -0,0 +1,118 @@
+//! Tests for the `# Query Parameters` doc section
+//!
+//! These tests verify that documented query parameters end up in the generated
+//! OpenAPI spec for destructured and opaque struct `Query<T>` extractors.
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{OpenApi, Parameter, ParameterData, ParameterSchemaOrContent};
+use rovo::extract::Query;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+struct ListParams {
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SearchParams {
+    q: Option<String>,
+    sort: Option<String>,
+}
+
+/// List items.
+///
+/// # Query Parameters
+///
+/// page: Page number to return (default: 1)
+/// limit: Maximum number of items (required)
+///
+/// # Responses
+///
+/// 200: Json<String> - A page of items
+#[rovo]
+async fn list_destructured(Query(ListParams { page, limit }): Query<ListParams>) -> Json<String> {
+    Json(format!("{page:?} {limit:?}"))
+}
+
+/// Search with an opaque struct binding.
+///
+/// # Query Parameters
+///
+/// q: Free text search (required)
+/// sort: Sort order (default: asc)
+///
+/// # Responses
+///
+/// 200: Json<String> - Search results
+#[rovo]
+async fn search_struct(Query(params): Query<SearchParams>) -> Json<String> {
+    Json(format!("{:?} {:?}", params.q, params.sort))
+}
+
+#[test]
+fn destructured_query_parameters_have_descriptions_and_markers() {
+    let spec = spec_for(Router::new().route("/items", get(list_destructured)));
+
+    let page = query_param(&spec, "/items", "page");
+    assert_eq!(page.description.as_deref(), Some("Page number to return"));
+    assert!(!page.required);
+    assert_eq!(schema_default(page), Some(serde_json::json!(1)));
+
+    let limit = query_param(&spec, "/items", "limit");
+    assert_eq!(
+        limit.description.as_deref(),
+        Some("Maximum number of items")
+    );
+    assert!(
+        limit.required,
+        "(required) marker should mark the parameter"
+    );
+}
+
+#[test]
+fn opaque_struct_query_parameters_are_updated() {
+    let spec = spec_for(Router::new().route("/search", get(search_struct)));
+
+    let q = query_param(&spec, "/search", "q");
+    assert_eq!(q.description.as_deref(), Some("Free text search"));
+    assert!(q.required);
+
+    let sort = query_param(&spec, "/search", "sort");
+    assert_eq!(sort.description.as_deref(), Some("Sort order"));
+    assert!(!sort.required);
+    assert_eq!(schema_default(sort), Some(serde_json::json!("asc")));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn query_param<'a>(spec: &'a OpenApi, path: &str, name: &str) -> &'a ParameterData {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let item = paths.get(path).unwrap().as_item().unwrap();
+    let op = item.get.as_ref().unwrap();
+
+    op.parameters
+        .iter()
+        .find_map(|p| match p.as_item() {
+            Some(Parameter::Query { parameter_data, .. }) if parameter_data.name == name => {
+                Some(parameter_data)
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Missing query parameter '{name}'"))
+}
+
+fn schema_default(param: &ParameterData) -> Option<serde_json::Value> {
+    match &param.format {
+        ParameterSchemaOrContent::Schema(schema) => schema.json_schema.get("default").cloned(),
+        ParameterSchemaOrContent::Content(_) => None,
+    }
+}
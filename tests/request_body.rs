@@ -0,0 +1,136 @@
+//! Tests for the `# Request Body` doc section
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{OpenApi, ReferenceOr, RequestBody};
+use rovo::extract::Form;
+use rovo::response::Json;
+use rovo::routing::post;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct CreateTodo {
+    title: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Login {
+    username: String,
+}
+
+/// Create a todo.
+///
+/// # Request Body
+///
+/// The todo item to create,
+/// without an ID.
+///
+/// Example: CreateTodo { title: "Buy milk".into(), done: false }
+///
+/// # Responses
+///
+/// 200: Json<String> - Created
+#[rovo]
+async fn create_todo(Json(todo): Json<CreateTodo>) -> Json<String> {
+    Json(format!("{}: {}", todo.title, todo.done))
+}
+
+/// Patch a todo.
+///
+/// # Request Body
+///
+/// Fields to change (optional)
+/// Content-Type: application/merge-patch+json
+/// Example:
+/// ```
+/// CreateTodo {
+///     title: "Buy oat milk".into(),
+///     done: true,
+/// }
+/// ```
+///
+/// # Responses
+///
+/// 200: Json<String> - Patched
+#[rovo]
+async fn patch_todo(Json(todo): Json<CreateTodo>) -> Json<String> {
+    Json(todo.title)
+}
+
+/// Log in.
+///
+/// # Request Body
+///
+/// Login form
+///
+/// # Responses
+///
+/// 200: Json<String> - Logged in
+#[rovo]
+async fn login(Form(form): Form<Login>) -> Json<String> {
+    Json(form.username)
+}
+
+#[test]
+fn description_and_example_are_documented() {
+    let spec = spec_for(Router::new().route("/todos", post(create_todo)));
+    let body = request_body(&spec, "/todos");
+
+    assert_eq!(
+        body.description.as_deref(),
+        Some("The todo item to create, without an ID.")
+    );
+    assert!(body.required, "Json<T> bodies stay required by default");
+
+    let media = &body.content["application/json"];
+    assert_eq!(
+        media.example,
+        Some(serde_json::json!({ "title": "Buy milk", "done": false }))
+    );
+}
+
+#[test]
+fn optional_marker_content_type_and_code_block_example() {
+    let spec = spec_for(Router::new().route("/todos", post(patch_todo)));
+    let body = request_body(&spec, "/todos");
+
+    assert_eq!(body.description.as_deref(), Some("Fields to change"));
+    assert!(!body.required);
+    assert_eq!(body.content.len(), 1);
+
+    let media = &body.content["application/merge-patch+json"];
+    assert!(media.schema.is_some(), "Schema is kept when renaming");
+    assert_eq!(
+        media.example,
+        Some(serde_json::json!({ "title": "Buy oat milk", "done": true }))
+    );
+}
+
+#[test]
+fn form_body_is_documented() {
+    let spec = spec_for(Router::new().route("/login", post(login)));
+    let body = request_body(&spec, "/login");
+
+    assert_eq!(body.description.as_deref(), Some("Login form"));
+    assert!(body
+        .content
+        .contains_key("application/x-www-form-urlencoded"));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn request_body<'a>(spec: &'a OpenApi, path: &str) -> &'a RequestBody {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = paths[path].as_item().unwrap().post.as_ref().unwrap();
+
+    match op.request_body.as_ref() {
+        Some(ReferenceOr::Item(body)) => body,
+        _ => panic!("Missing request body for '{path}'"),
+    }
+}
@@ -0,0 +1,284 @@
+//! Tests for validating requests against the spec with `Router::validate_requests`
+#![cfg(feature = "validation")]
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::OpenApi;
+use rovo::extract::{Path, Query};
+use rovo::response::Json;
+use rovo::routing::{get, post};
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, RequestValidation, Router, ViolationLocation};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum Status {
+    Active,
+    Suspended,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct ListParams {
+    #[schemars(range(min = 1, max = 100))]
+    limit: Option<u32>,
+    status: Option<Status>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+struct NewUser {
+    #[schemars(email)]
+    email: String,
+    #[schemars(length(min = 3))]
+    name: String,
+    #[schemars(regex(pattern = r"^[a-z]{2}$"))]
+    locale: Option<String>,
+}
+
+/// Get a user.
+///
+/// # Path Parameters
+///
+/// id: The user's ID
+///
+/// # Responses
+///
+/// 200: Json<u64> - The user's ID
+#[rovo]
+async fn get_user(Path(id): Path<u64>) -> impl IntoApiResponse {
+    Json(id)
+}
+
+/// List users.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - The users
+#[rovo]
+async fn list_users(Query(_params): Query<ListParams>) -> impl IntoApiResponse {
+    Json(Vec::<String>::new())
+}
+
+/// Create a user.
+///
+/// # Responses
+///
+/// 200: Json<NewUser> - The created user
+#[rovo]
+async fn create_user(Json(user): Json<NewUser>) -> impl IntoApiResponse {
+    Json(user)
+}
+
+#[tokio::test]
+async fn valid_requests_reach_the_handler() {
+    let response = send(app(), get_request("/users/42")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response).await, json!(42));
+
+    let response = send(app(), get_request("/users?limit=10&status=active")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let user = json!({ "email": "bob@example.com", "name": "Bob", "locale": "en" });
+    let response = send(app(), post_json("/users", &user)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response).await, user);
+}
+
+#[tokio::test]
+async fn invalid_path_parameter_is_rejected() {
+    let response = send(app(), get_request("/users/abc")).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/problem+json"
+    );
+    let problem = body(response).await;
+    assert_eq!(problem["type"], "about:blank");
+    assert_eq!(problem["title"], "Request validation failed");
+    assert_eq!(problem["status"], 422);
+    assert_eq!(problem["instance"], "/users/abc");
+    let errors = errors(&problem);
+    assert_eq!(errors.len(), 1, "{problem}");
+    assert_eq!(errors[0]["in"], "path");
+    assert_eq!(errors[0]["name"], "id");
+}
+
+#[tokio::test]
+async fn every_invalid_query_parameter_is_listed() {
+    let response = send(app(), get_request("/users?limit=1000&status=deleted")).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem = body(response).await;
+    let names: Vec<&Value> = errors(&problem)
+        .iter()
+        .map(|error| &error["name"])
+        .collect();
+    assert_eq!(names, [&json!("limit"), &json!("status")], "{problem}");
+    assert!(errors(&problem).iter().all(|error| error["in"] == "query"));
+}
+
+#[tokio::test]
+async fn body_formats_lengths_and_patterns_are_checked() {
+    let user = json!({ "email": "bob", "name": "Bo", "locale": "english" });
+    let response = send(app(), post_json("/users", &user)).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem = body(response).await;
+    let mut pointers: Vec<&str> = errors(&problem)
+        .iter()
+        .map(|error| {
+            assert_eq!(error["in"], "body");
+            assert!(error.get("name").is_none());
+            error["pointer"].as_str().unwrap()
+        })
+        .collect();
+    pointers.sort_unstable();
+    assert_eq!(pointers, ["/email", "/locale", "/name"], "{problem}");
+}
+
+#[tokio::test]
+async fn missing_required_body_is_rejected() {
+    let request = Request::post("/users").body(Body::empty()).unwrap();
+    let response = send(app(), request).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem = body(response).await;
+    assert_eq!(errors(&problem)[0]["in"], "body");
+}
+
+#[tokio::test]
+async fn malformed_json_is_left_to_the_extractor() {
+    let request = Request::post("/users")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from("{not json"))
+        .unwrap();
+    let response = send(app(), request).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn oversized_body_is_rejected() {
+    let app = Router::new()
+        .route("/users", post(create_user))
+        .with_oas(OpenApi::default())
+        .validate_requests(RequestValidation::new().body_limit(16))
+        .finish();
+
+    let user = json!({ "email": "bob@example.com", "name": "Bob" });
+    let response = send(app, post_json("/users", &user)).await;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn problem_response_is_configurable() {
+    let app = Router::new()
+        .route("/users/{id}", get(get_user))
+        .with_oas(OpenApi::default())
+        .validate_requests(
+            RequestValidation::new()
+                .status(StatusCode::BAD_REQUEST)
+                .problem_type("https://example.com/problems/validation")
+                .title("Invalid request"),
+        )
+        .finish();
+
+    let response = send(app, get_request("/users/abc")).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let problem = body(response).await;
+    assert_eq!(problem["type"], "https://example.com/problems/validation");
+    assert_eq!(problem["title"], "Invalid request");
+    assert_eq!(problem["status"], 400);
+}
+
+#[tokio::test]
+async fn custom_responder_gets_the_violations() {
+    let app = Router::new()
+        .route("/users/{id}", get(get_user))
+        .with_oas(OpenApi::default())
+        .validate_requests(RequestValidation::new().respond_with(|violations| {
+            assert_eq!(violations[0].location, ViolationLocation::Path);
+            assert_eq!(violations[0].name.as_deref(), Some("id"));
+            (StatusCode::IM_A_TEAPOT, violations[0].to_string()).into_response()
+        }))
+        .finish();
+
+    let response = send(app, get_request("/users/abc")).await;
+
+    assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+}
+
+#[tokio::test]
+async fn undocumented_routes_and_spec_are_not_validated() {
+    let app = Router::new()
+        .route("/users/{id}", get(get_user))
+        .route("/health", axum::routing::get(|| async { "ok" }))
+        .with_oas(OpenApi::default())
+        .validate_requests(RequestValidation::new())
+        .finish();
+
+    let response = send(app.clone(), get_request("/health?limit=abc")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(app, get_request("/api.json")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn without_validation_requests_are_not_checked() {
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(OpenApi::default())
+        .finish();
+
+    let response = send(app, get_request("/users?limit=1000")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn app() -> axum::Router {
+    Router::new()
+        .route("/users", get(list_users).post(create_user))
+        .route("/users/{id}", get(get_user))
+        .with_oas(OpenApi::default())
+        .validate_requests(RequestValidation::new())
+        .finish()
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+fn post_json(uri: &str, value: &Value) -> Request<Body> {
+    Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(value.to_string()))
+        .unwrap()
+}
+
+async fn send(app: axum::Router, request: Request<Body>) -> Response {
+    app.oneshot(request).await.unwrap()
+}
+
+async fn body(response: Response) -> Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+fn errors(problem: &Value) -> &Vec<Value> {
+    problem["errors"].as_array().unwrap()
+}
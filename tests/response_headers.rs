@@ -0,0 +1,101 @@
+//! Tests for `header` lines under entries in the `# Responses` doc section
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{Header, OpenApi, ParameterSchemaOrContent, ReferenceOr, Response};
+use rovo::http::StatusCode;
+use rovo::response::Json;
+use rovo::routing::post;
+use rovo::{rovo, Router};
+
+/// Create an item.
+///
+/// # Responses
+///
+/// 201: Json<String> - Item created
+///   header Location: String (required) - URL of the created resource
+///   header ETag: String - Version of the created resource
+/// 429: () - Too many requests, try again later
+///   header Retry-After: u32 - Seconds to wait before retrying
+/// 400: () - Invalid item,
+/// header values are not documented for this response
+#[rovo]
+async fn create_item() -> (StatusCode, Json<String>) {
+    (StatusCode::CREATED, Json("created".to_string()))
+}
+
+#[test]
+fn response_headers_are_documented() {
+    let spec = spec_for(Router::new().route("/items", post(create_item)));
+
+    let created = response(&spec, 201);
+    assert_eq!(created.headers.len(), 2);
+
+    let location = header(created, "Location");
+    assert_eq!(
+        location.description.as_deref(),
+        Some("URL of the created resource")
+    );
+    assert!(location.required);
+    assert_eq!(schema_type(location), Some("string"));
+
+    let etag = header(created, "ETag");
+    assert_eq!(
+        etag.description.as_deref(),
+        Some("Version of the created resource")
+    );
+    assert!(!etag.required);
+
+    let retry_after = header(response(&spec, 429), "Retry-After");
+    assert_eq!(schema_type(retry_after), Some("integer"));
+}
+
+#[test]
+fn unindented_header_text_continues_the_description() {
+    let spec = spec_for(Router::new().route("/items", post(create_item)));
+
+    let bad_request = response(&spec, 400);
+    assert!(bad_request.headers.is_empty());
+    assert_eq!(
+        bad_request.description,
+        "Invalid item, header values are not documented for this response"
+    );
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn response(spec: &OpenApi, status: u16) -> &Response {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = paths["/items"].as_item().unwrap().post.as_ref().unwrap();
+    let responses = op.responses.as_ref().unwrap();
+
+    responses
+        .responses
+        .iter()
+        .find_map(|(code, res)| match (code, res) {
+            (rovo::aide::openapi::StatusCode::Code(c), ReferenceOr::Item(res)) if *c == status => {
+                Some(res)
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Missing response {status}"))
+}
+
+fn header<'a>(response: &'a Response, name: &str) -> &'a Header {
+    match response.headers.get(name) {
+        Some(ReferenceOr::Item(header)) => header,
+        _ => panic!("Missing response header '{name}'"),
+    }
+}
+
+fn schema_type(header: &Header) -> Option<&str> {
+    match &header.format {
+        ParameterSchemaOrContent::Schema(schema) => {
+            schema.json_schema.get("type").and_then(|t| t.as_str())
+        }
+        ParameterSchemaOrContent::Content(_) => None,
+    }
+}
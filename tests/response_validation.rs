@@ -0,0 +1,147 @@
+//! Tests for checking responses against the documented ones with
+//! `Router::validate_responses`
+#![cfg(feature = "validation")]
+
+mod common;
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use common::Logs;
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::OpenApi;
+use rovo::extract::Path;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, ResponseValidation, Router};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+#[derive(Serialize, JsonSchema)]
+struct User {
+    id: u64,
+    #[schemars(email)]
+    email: String,
+}
+
+/// Get a user.
+///
+/// # Path Parameters
+///
+/// id: The user's ID
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - No such user
+#[rovo]
+async fn get_user(Path(id): Path<u64>) -> impl IntoApiResponse {
+    match id {
+        0 => Json(json!({ "id": "zero", "email": "nobody" })).into_response(),
+        1 => StatusCode::NOT_FOUND.into_response(),
+        2 => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        3 => ([(header::CONTENT_TYPE, "text/plain")], "user 3".to_string()).into_response(),
+        _ => Json(User {
+            id,
+            email: "bob@example.com".to_string(),
+        })
+        .into_response(),
+    }
+}
+
+#[tokio::test]
+async fn documented_responses_pass() {
+    let response = send(strict_app(), "/users/42").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        body(response).await,
+        json!({ "id": 42, "email": "bob@example.com" })
+    );
+
+    let response = send(strict_app(), "/users/1").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+#[should_panic(expected = "status 500 is not documented (documented: 200, 404)")]
+async fn undocumented_status_panics() {
+    send(strict_app(), "/users/2").await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "response of GET /users/{id} does not match its documentation")]
+async fn body_not_matching_the_schema_panics() {
+    send(strict_app(), "/users/0").await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "content type `text/plain` is not documented for status 200")]
+async fn undocumented_content_type_panics() {
+    send(strict_app(), "/users/3").await;
+}
+
+#[tokio::test]
+async fn mismatches_are_logged_by_default() {
+    let logs = Logs::default();
+    let _guard = logs.capture();
+
+    let response = send(app(ResponseValidation::new()), "/users/0").await;
+
+    // The response is passed on unchanged
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        body(response).await,
+        json!({ "id": "zero", "email": "nobody" })
+    );
+
+    let logs = logs.contents();
+    assert!(logs.contains("WARN"), "{logs}");
+    assert!(logs.contains("body at /id"), "{logs}");
+    assert!(logs.contains("body at /email"), "{logs}");
+}
+
+#[tokio::test]
+async fn undocumented_routes_are_not_checked() {
+    let app = Router::new()
+        .route(
+            "/health",
+            axum::routing::get(|| async { StatusCode::IM_A_TEAPOT }),
+        )
+        .with_oas(OpenApi::default())
+        .validate_responses(ResponseValidation::new().panic_on_mismatch())
+        .finish();
+
+    let response = send(app, "/health").await;
+    assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn app(validation: ResponseValidation) -> axum::Router {
+    Router::new()
+        .route("/users/{id}", get(get_user))
+        .with_oas(OpenApi::default())
+        .validate_responses(validation)
+        .finish()
+}
+
+/// An app panicking on mismatched responses
+fn strict_app() -> axum::Router {
+    app(ResponseValidation::new().panic_on_mismatch())
+}
+
+async fn send(app: axum::Router, uri: &str) -> Response {
+    let request = Request::get(uri).body(Body::empty()).unwrap();
+    app.oneshot(request).await.unwrap()
+}
+
+async fn body(response: Response) -> Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
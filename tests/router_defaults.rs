@@ -0,0 +1,132 @@
+//! Tests for router-level default security, responses and tags
+
+mod common;
+
+use common::{op, response_description, spec_for};
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::{OpenApi, Operation};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router, SecurityScheme};
+use serde::Serialize;
+
+#[derive(Serialize, JsonSchema)]
+struct ApiError {
+    message: String,
+}
+
+/// List users.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - All users
+#[rovo]
+async fn list_users() -> impl IntoApiResponse {
+    Json(Vec::<String>::new())
+}
+
+/// Delete everything.
+///
+/// # Responses
+///
+/// 204: () - Deleted
+/// 500: Json<ApiError> - The database refused
+///
+/// # Metadata
+///
+/// @tag danger
+/// @security root_token
+#[rovo]
+async fn purge() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Health check.
+///
+/// # Responses
+///
+/// 200: () - Healthy
+#[rovo]
+async fn health() -> impl IntoApiResponse {
+    Json(())
+}
+
+#[test]
+fn defaults_apply_to_operations_without_their_own() {
+    let spec = spec_for(admin_router());
+    let op = op(&spec, "/users", |item| item.get.as_ref());
+
+    assert_eq!(op.tags, vec!["admin"]);
+    assert_eq!(security_schemes(op), vec!["admin_token"]);
+    assert_eq!(response_description(op, 500), Some("Internal server error"));
+    assert_eq!(response_description(op, 403), Some("Forbidden"));
+}
+
+#[test]
+fn handlers_override_defaults() {
+    let spec = spec_for(admin_router());
+    let op = op(&spec, "/purge", |item| item.delete.as_ref());
+
+    assert_eq!(op.tags, vec!["danger"]);
+    assert_eq!(security_schemes(op), vec!["root_token"]);
+    assert_eq!(response_description(op, 500), Some("The database refused"));
+    assert_eq!(response_description(op, 403), Some("Forbidden"));
+}
+
+#[test]
+fn defaults_apply_to_nested_routers() {
+    let spec = spec_for(
+        Router::new()
+            .route("/health", get(health))
+            .nest("/admin", admin_router())
+            .default_tag("api")
+            .default_response::<()>(503, "Maintenance"),
+    );
+
+    let health_op = op(&spec, "/health", |item| item.get.as_ref());
+    assert_eq!(health_op.tags, vec!["api"]);
+    assert!(health_op.security.is_empty());
+    assert_eq!(response_description(health_op, 503), Some("Maintenance"));
+
+    // The nested router's own defaults win over the parent's
+    let users = op(&spec, "/admin/users", |item| item.get.as_ref());
+    assert_eq!(users.tags, vec!["admin"]);
+    assert_eq!(security_schemes(users), vec!["admin_token"]);
+    assert_eq!(response_description(users, 503), Some("Maintenance"));
+}
+
+#[test]
+fn defaults_survive_with_state() {
+    let router = Router::<()>::new()
+        .nest("/admin", admin_router().with_state(()))
+        .default_tag("api");
+
+    let mut api = OpenApi::default();
+    let _ = router.finish_api(&mut api);
+    let users = op(&api, "/admin/users", |item| item.get.as_ref());
+    assert_eq!(users.tags, vec!["admin"]);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn admin_router() -> Router<()> {
+    Router::new()
+        .route("/users", get(list_users))
+        .route("/purge", rovo::routing::delete(purge))
+        .security_scheme("admin_token", SecurityScheme::bearer())
+        .security_scheme("root_token", SecurityScheme::bearer())
+        .default_security("admin_token")
+        .default_tag("admin")
+        .default_response::<Json<ApiError>>(500, "Internal server error")
+        .default_response::<()>(403, "Forbidden")
+}
+
+fn security_schemes(op: &Operation) -> Vec<&str> {
+    op.security
+        .iter()
+        .flat_map(|requirement| requirement.keys().map(String::as_str))
+        .collect()
+}
@@ -2,7 +2,7 @@ use rovo::aide::openapi::OpenApi;
 use rovo::extract::State;
 use rovo::http::StatusCode;
 use rovo::response::Json;
-use rovo::routing::{get, post, put};
+use rovo::routing::{get, head, options, post, put, trace};
 use rovo::schemars::JsonSchema;
 use rovo::{rovo, Router};
 use serde::Serialize;
@@ -56,6 +56,36 @@ async fn update_item(State(_state): State<AppState>) -> Json<Item> {
     Json(Item::default())
 }
 
+/// Check item existence
+///
+/// # Responses
+///
+/// 200: () - Item exists
+#[rovo]
+async fn probe_item(State(_state): State<AppState>) -> StatusCode {
+    StatusCode::OK
+}
+
+/// Describe allowed methods
+///
+/// # Responses
+///
+/// 204: () - Allowed methods listed in the Allow header
+#[rovo]
+async fn item_options(State(_state): State<AppState>) -> StatusCode {
+    StatusCode::NO_CONTENT
+}
+
+/// Echo the request
+///
+/// # Responses
+///
+/// 200: () - Request echoed
+#[rovo]
+async fn trace_item(State(_state): State<AppState>) -> StatusCode {
+    StatusCode::OK
+}
+
 #[test]
 fn test_router_default() {
     let router: Router<AppState> = Router::default();
@@ -127,6 +157,85 @@ fn test_put_routing() {
     assert!(item_path.put.is_some(), "Should have PUT method");
 }
 
+#[test]
+fn test_head_options_trace_routing() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/probe", head(probe_item))
+        .route("/preflight", options(item_options))
+        .route("/trace", trace(trace_item))
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+
+    let probe = get_path_item(paths.get("/probe").unwrap());
+    assert_eq!(
+        probe.head.as_ref().unwrap().summary.as_deref(),
+        Some("Check item existence")
+    );
+    assert!(get_path_item(paths.get("/preflight").unwrap())
+        .options
+        .is_some());
+    assert!(get_path_item(paths.get("/trace").unwrap()).trace.is_some());
+}
+
+#[test]
+fn test_method_chaining_with_head_options_trace() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route(
+            "/items",
+            get(get_item)
+                .head(probe_item)
+                .options(item_options)
+                .trace(trace_item),
+        )
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let items_path = get_path_item(paths.get("/items").unwrap());
+
+    assert!(items_path.get.is_some(), "Should have GET method");
+    assert!(items_path.head.is_some(), "Should have HEAD method");
+    assert!(items_path.options.is_some(), "Should have OPTIONS method");
+    assert!(items_path.trace.is_some(), "Should have TRACE method");
+}
+
+#[tokio::test]
+async fn test_options_route_is_served() {
+    use tower::ServiceExt;
+
+    let app = Router::new()
+        .route("/items", options(item_options))
+        .with_state(AppState)
+        .finish();
+
+    let response = app
+        .oneshot(
+            axum::http::Request::builder()
+                .method("OPTIONS")
+                .uri("/items")
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
 #[test]
 fn test_method_chaining_with_get() {
     let state = AppState;
@@ -4,7 +4,7 @@ use rovo::http::StatusCode;
 use rovo::response::{IntoResponse, Json, Response};
 use rovo::routing::{delete, get, patch};
 use rovo::schemars::JsonSchema;
-use rovo::{rovo, Router};
+use rovo::{rovo, Router, SecurityScheme};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone)]
@@ -359,6 +359,7 @@ fn test_security_annotation() {
 
     let app = Router::new()
         .route("/protected", get(protected_items))
+        .security_scheme("bearer_auth", SecurityScheme::bearer_jwt())
         .with_oas(api)
         .with_state(state)
         .finish();
@@ -0,0 +1,101 @@
+//! Tests for metadata given as `#[rovo(...)]` attribute arguments
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::openapi::{OpenApi, Operation};
+use rovo::http::StatusCode;
+use rovo::routing::get;
+use rovo::{rovo, Router, SecurityScheme};
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: () - User found
+#[rovo(tag = "users", tag = "admin", security = "bearer", id = "getUser")]
+async fn get_user() -> StatusCode {
+    StatusCode::OK
+}
+
+/// List users.
+///
+/// # Responses
+///
+/// 200: () - Users
+///
+/// # Metadata
+///
+/// @tag users
+/// @security api_key
+/// @id listUsers
+#[rovo(tag = "users", security = "bearer", id = "listUsers")]
+async fn list_users() -> StatusCode {
+    StatusCode::OK
+}
+
+/// Internal endpoint.
+#[rovo(hidden)]
+async fn internal() -> StatusCode {
+    StatusCode::OK
+}
+
+#[test]
+fn attribute_metadata_is_applied() {
+    let spec = spec_for(with_security_schemes(
+        Router::new().route("/users/me", get(get_user)),
+    ));
+    let op = get_op(&spec, "/users/me");
+
+    assert_eq!(op.operation_id.as_deref(), Some("getUser"));
+    assert_eq!(op.tags, vec!["users", "admin"]);
+    assert_eq!(security_schemes(op), vec!["bearer"]);
+}
+
+#[test]
+fn attribute_metadata_is_merged_with_doc_metadata() {
+    let spec = spec_for(with_security_schemes(
+        Router::new().route("/users", get(list_users)),
+    ));
+    let op = get_op(&spec, "/users");
+
+    assert_eq!(op.operation_id.as_deref(), Some("listUsers"));
+    assert_eq!(op.tags, vec!["users"], "Duplicate tags are merged");
+    assert_eq!(security_schemes(op), vec!["api_key", "bearer"]);
+}
+
+#[test]
+fn hidden_attribute_hides_operation() {
+    let spec = spec_for(with_security_schemes(
+        Router::new().route("/internal", get(internal)),
+    ));
+    let paths = &spec.paths.as_ref().unwrap().paths;
+
+    assert!(paths
+        .get("/internal")
+        .and_then(|item| item.as_item())
+        .is_none_or(|item| item.get.is_none()));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn with_security_schemes(router: Router<()>) -> Router<()> {
+    router
+        .security_scheme("bearer", SecurityScheme::bearer())
+        .security_scheme("api_key", SecurityScheme::api_key_header("X-Api-Key"))
+}
+
+fn get_op<'a>(spec: &'a OpenApi, path: &str) -> &'a Operation {
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    paths[path].as_item().unwrap().get.as_ref().unwrap()
+}
+
+fn security_schemes(op: &Operation) -> Vec<&str> {
+    op.security
+        .iter()
+        .flat_map(|requirement| requirement.keys())
+        .map(String::as_str)
+        .collect()
+}
@@ -515,7 +515,7 @@ fn test_example_starts_next_line() {
 ///     completed: false
 /// }
 /// ```
-#[rovo]
+#[rovo(allow(undocumented_path_params))]
 async fn get_todo_code_block_unmarked(
     State(_app): State<AppState>,
     Path(_id): Path<Uuid>,
@@ -549,7 +549,7 @@ fn test_code_block_unmarked() {
 ///     completed: false
 /// }
 /// ```
-#[rovo]
+#[rovo(allow(undocumented_path_params))]
 async fn get_todo_code_block_rust(
     State(_app): State<AppState>,
     Path(_id): Path<Uuid>,
@@ -583,7 +583,7 @@ fn test_code_block_rust() {
 ///     completed: false
 /// }
 /// ```
-#[rovo]
+#[rovo(allow(undocumented_path_params))]
 async fn get_todo_code_block_rs(
     State(_app): State<AppState>,
     Path(_id): Path<Uuid>,
@@ -616,7 +616,7 @@ fn test_code_block_rs() {
 ///     completed: false
 /// }
 /// ```
-#[rovo]
+#[rovo(allow(undocumented_path_params))]
 async fn get_todo_code_block_same_line(
     State(_app): State<AppState>,
     Path(_id): Path<Uuid>,
@@ -29,6 +29,17 @@ struct Wrapper<T> {
     inner: T,
 }
 
+/// # Account
+///
+/// A user's account.
+///
+/// Closed accounts are kept for a year
+/// before they are deleted.
+#[derive(Debug, JsonSchema)]
+struct TitledStruct {
+    id: u64,
+}
+
 #[test]
 fn derive_produces_valid_json_schema() {
     let schema = rovo::schemars::SchemaGenerator::default().into_root_schema_for::<BasicStruct>();
@@ -58,3 +69,25 @@ fn derive_works_with_generics() {
     let json = serde_json::to_string(&schema).unwrap();
     assert!(json.contains("Wrapper"));
 }
+
+#[test]
+fn doc_heading_becomes_the_title() {
+    let schema = rovo::schemars::SchemaGenerator::default().into_root_schema_for::<TitledStruct>();
+
+    assert_eq!(schema.get("title").unwrap(), "Account");
+    assert_eq!(
+        schema.get("description").unwrap(),
+        "A user's account.\n\nClosed accounts are kept for a year\nbefore they are deleted."
+    );
+}
+
+#[test]
+fn doc_without_heading_is_the_description() {
+    let schema = rovo::schemars::SchemaGenerator::default().into_root_schema_for::<BasicStruct>();
+
+    assert_eq!(schema.get("title").unwrap(), "BasicStruct");
+    assert_eq!(
+        schema.get("description").unwrap(),
+        "Verifies `#[derive(JsonSchema)]` works without any helper attributes."
+    );
+}
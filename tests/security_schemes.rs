@@ -0,0 +1,140 @@
+//! Tests for security schemes registered with `Router::security_scheme`
+
+mod common;
+
+use common::spec_for;
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::{OpenApi, ReferenceOr, SecurityScheme as OasSecurityScheme};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Router, SecurityScheme};
+
+/// Get the current user.
+///
+/// # Responses
+///
+/// 200: () - The user
+///
+/// # Metadata
+///
+/// @security bearer_auth
+#[rovo]
+async fn me() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// List reports.
+///
+/// # Responses
+///
+/// 200: () - All reports
+///
+/// # Metadata
+///
+/// @security oauth
+#[rovo]
+async fn reports() -> impl IntoApiResponse {
+    Json(())
+}
+
+#[test]
+fn schemes_are_added_to_components() {
+    let spec = spec_for(
+        Router::new()
+            .route("/me", get(me))
+            .security_scheme("bearer_auth", SecurityScheme::bearer_jwt())
+            .security_scheme(
+                "api_key",
+                SecurityScheme::api_key_header("X-Api-Key").description("Issued per client"),
+            ),
+    );
+
+    let json = serde_json::to_value(&spec).unwrap();
+    let schemes = &json["components"]["securitySchemes"];
+    assert_eq!(
+        schemes["bearer_auth"],
+        serde_json::json!({ "type": "http", "scheme": "bearer", "bearerFormat": "JWT" })
+    );
+    assert_eq!(
+        schemes["api_key"],
+        serde_json::json!({
+            "type": "apiKey",
+            "in": "header",
+            "name": "X-Api-Key",
+            "description": "Issued per client",
+        })
+    );
+}
+
+#[test]
+fn oauth2_scopes_are_documented() {
+    let spec = spec_for(
+        Router::new()
+            .route("/reports", get(reports))
+            .security_scheme(
+                "oauth",
+                SecurityScheme::oauth2_client_credentials(
+                    "https://auth.example.com/token",
+                    [("reports:read", "Read reports")],
+                ),
+            ),
+    );
+
+    let json = serde_json::to_value(&spec).unwrap();
+    assert_eq!(
+        json["components"]["securitySchemes"]["oauth"]["flows"]["clientCredentials"]["scopes"],
+        serde_json::json!({ "reports:read": "Read reports" })
+    );
+}
+
+#[test]
+fn schemes_of_nested_routers_are_registered() {
+    let spec = spec_for(
+        Router::<()>::new().nest(
+            "/api",
+            Router::new()
+                .route("/me", get(me))
+                .security_scheme("bearer_auth", SecurityScheme::bearer_jwt())
+                .with_state(()),
+        ),
+    );
+
+    let components = spec.components.unwrap();
+    assert!(components.security_schemes.contains_key("bearer_auth"));
+}
+
+#[test]
+fn schemes_defined_on_the_spec_are_accepted() {
+    let mut api = OpenApi::default();
+    api.components
+        .get_or_insert_with(Default::default)
+        .security_schemes
+        .insert(
+            "bearer_auth".to_string(),
+            ReferenceOr::Item(SecurityScheme::bearer().into_inner()),
+        );
+
+    let _ = Router::<()>::new()
+        .route("/me", get(me))
+        .finish_api(&mut api);
+}
+
+#[test]
+#[should_panic(expected = "GET /me requires `bearer_auth`")]
+fn undefined_schemes_panic_at_finish() {
+    let _ = spec_for(Router::new().route("/me", get(me)));
+}
+
+#[test]
+fn aide_schemes_convert_into_security_schemes() {
+    let scheme: SecurityScheme = OasSecurityScheme::MutualTls {
+        description: None,
+        extensions: Default::default(),
+    }
+    .into();
+
+    assert!(matches!(
+        scheme.into_inner(),
+        OasSecurityScheme::MutualTls { .. }
+    ));
+}
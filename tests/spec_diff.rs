@@ -0,0 +1,414 @@
+//! Tests for `rovo::diff`, the breaking change detector, and the `rovo-diff` binary
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use rovo::aide::openapi::OpenApi;
+use rovo::diff::{compare, Change};
+use rovo::routing::get;
+use rovo::{Format, Router};
+
+/// The API as released
+mod v1 {
+    use rovo::extract::Query;
+    use rovo::response::Json;
+    use rovo::rovo;
+    use rovo::schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    pub struct ListParams {
+        pub page: Option<u32>,
+    }
+
+    #[derive(Deserialize, Serialize, JsonSchema)]
+    pub enum Role {
+        Admin,
+        Member,
+        Guest,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    pub struct User {
+        pub id: u64,
+        pub name: String,
+        pub email: String,
+        pub role: Role,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    pub struct NewUser {
+        pub name: String,
+        pub role: Role,
+    }
+
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - All users
+    #[rovo]
+    pub async fn list_users(Query(_params): Query<ListParams>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - The new user
+    #[rovo]
+    pub async fn create_user(Json(_user): Json<NewUser>) -> Json<User> {
+        unimplemented!()
+    }
+
+    /// Remove every user.
+    ///
+    /// # Responses
+    ///
+    /// 204: () - Users removed
+    #[rovo]
+    pub async fn purge_users() {}
+}
+
+/// The API with breaking changes
+mod v2 {
+    use rovo::extract::Query;
+    use rovo::response::Json;
+    use rovo::rovo;
+    use rovo::schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    pub struct ListParams {
+        pub page: Option<u32>,
+        pub tenant: String,
+    }
+
+    #[derive(Deserialize, Serialize, JsonSchema)]
+    pub enum Role {
+        Admin,
+        Member,
+        Owner,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    pub struct User {
+        pub id: String,
+        pub name: Option<String>,
+        pub role: Role,
+        pub created_at: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    pub struct NewUser {
+        pub name: String,
+        pub role: Role,
+        pub invited_by: Option<u64>,
+    }
+
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - All users
+    #[rovo]
+    pub async fn list_users(Query(_params): Query<ListParams>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - The new user
+    #[rovo]
+    pub async fn create_user(Json(_user): Json<NewUser>) -> Json<User> {
+        unimplemented!()
+    }
+
+    /// Count users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<u64> - Number of users
+    #[rovo]
+    pub async fn count_users() -> Json<u64> {
+        Json(0)
+    }
+}
+
+/// Recursive types must not send the comparison into a loop
+mod tree {
+    use rovo::response::Json;
+    use rovo::rovo;
+    use rovo::schemars::JsonSchema;
+    use serde::Serialize;
+
+    #[derive(Serialize, JsonSchema)]
+    pub struct Node {
+        pub name: String,
+        pub children: Vec<Node>,
+        pub parent: Option<Box<Node>>,
+    }
+
+    /// Get the tree.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Node> - The root node
+    #[rovo]
+    pub async fn get_tree() -> Json<Node> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn identical_specs_have_no_changes() {
+    let diff = compare(&v1_spec(), &v1_spec());
+
+    assert!(diff.is_empty());
+    assert!(!diff.is_breaking());
+    assert_eq!(diff.to_string(), "No changes\n");
+}
+
+#[test]
+fn removed_operation_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(diff.is_breaking());
+    assert!(has(&diff.changes, true, "DELETE /users: operation removed"));
+}
+
+#[test]
+fn added_operation_is_not_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        false,
+        "GET /users/count: operation added"
+    ));
+}
+
+#[test]
+fn new_required_parameter_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "GET /users: query parameter `tenant`: added as required"
+    ));
+}
+
+#[test]
+fn narrowed_request_enum_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "POST /users: request body `role`: enum values removed: \"Guest\""
+    ));
+    assert!(has(
+        &diff.changes,
+        false,
+        "POST /users: request body `role`: enum values added: \"Owner\""
+    ));
+}
+
+#[test]
+fn widened_response_enum_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "GET /users: response 200 `[].role`: enum values added: \"Owner\""
+    ));
+    assert!(has(
+        &diff.changes,
+        false,
+        "GET /users: response 200 `[].role`: enum values removed: \"Guest\""
+    ));
+}
+
+#[test]
+fn removed_response_field_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "POST /users: response 201: property `email` removed"
+    ));
+    assert!(has(
+        &diff.changes,
+        false,
+        "POST /users: response 201: required property `created_at` added"
+    ));
+}
+
+#[test]
+fn changed_type_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "POST /users: response 201 `id`: type changed from integer to string"
+    ));
+}
+
+#[test]
+fn nullable_response_field_is_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        true,
+        "POST /users: response 201 `name`: may now be null"
+    ));
+    assert!(has(
+        &diff.changes,
+        true,
+        "POST /users: response 201: property `name` became optional"
+    ));
+}
+
+#[test]
+fn new_optional_request_field_is_not_breaking() {
+    let diff = compare(&v1_spec(), &v2_spec());
+
+    assert!(has(
+        &diff.changes,
+        false,
+        "POST /users: request body: property `invited_by` added"
+    ));
+}
+
+#[test]
+fn reversed_comparison_swaps_classification() {
+    let diff = compare(&v2_spec(), &v1_spec());
+
+    assert!(has(&diff.changes, false, "DELETE /users: operation added"));
+    assert!(has(
+        &diff.changes,
+        false,
+        "GET /users: query parameter `tenant`: removed"
+    ));
+    assert!(has(
+        &diff.changes,
+        false,
+        "POST /users: request body: property `invited_by` removed"
+    ));
+}
+
+#[test]
+fn display_groups_changes() {
+    let output = compare(&v1_spec(), &v2_spec()).to_string();
+
+    let breaking = output.find("Breaking changes:\n").unwrap();
+    let non_breaking = output.find("Non-breaking changes:\n").unwrap();
+    assert!(breaking < non_breaking);
+    assert!(output.contains("\n  - DELETE /users: operation removed\n"));
+}
+
+#[test]
+fn recursive_schemas_terminate() {
+    let spec = || {
+        Router::<()>::new()
+            .route("/tree", get(tree::get_tree))
+            .with_oas(test_api())
+            .to_openapi()
+            .unwrap()
+    };
+
+    assert!(compare(&spec(), &spec()).is_empty());
+}
+
+#[test]
+fn cli_reports_breaking_changes() {
+    let old = temp_path("old.json");
+    let new = temp_path("new.yaml");
+    v1_router().write_spec(&old, Format::Json).unwrap();
+    v2_router().write_spec(&new, Format::Yaml).unwrap();
+
+    let breaking = Command::new(env!("CARGO_BIN_EXE_rovo-diff"))
+        .args([&old, &new])
+        .output()
+        .unwrap();
+    let unchanged = Command::new(env!("CARGO_BIN_EXE_rovo-diff"))
+        .args([&old, &old])
+        .output()
+        .unwrap();
+    let missing = Command::new(env!("CARGO_BIN_EXE_rovo-diff"))
+        .args([&old, &temp_path("missing.json")])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&old).unwrap();
+    std::fs::remove_file(&new).unwrap();
+
+    assert_eq!(breaking.status.code(), Some(1));
+    let stdout = String::from_utf8(breaking.stdout).unwrap();
+    assert!(stdout.contains("DELETE /users: operation removed"));
+    assert_eq!(unchanged.status.code(), Some(0));
+    assert_eq!(String::from_utf8(unchanged.stdout).unwrap(), "No changes\n");
+    assert_eq!(missing.status.code(), Some(2));
+    assert!(String::from_utf8(missing.stderr)
+        .unwrap()
+        .contains("failed to read"));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn test_api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
+
+fn v1_router() -> Router<()> {
+    Router::new()
+        .route(
+            "/users",
+            get(v1::list_users)
+                .post(v1::create_user)
+                .delete(v1::purge_users),
+        )
+        .with_oas(test_api())
+}
+
+fn v2_router() -> Router<()> {
+    Router::new()
+        .route("/users", get(v2::list_users).post(v2::create_user))
+        .route("/users/count", get(v2::count_users))
+        .with_oas(test_api())
+}
+
+fn v1_spec() -> OpenApi {
+    v1_router().to_openapi().unwrap()
+}
+
+fn v2_spec() -> OpenApi {
+    v2_router().to_openapi().unwrap()
+}
+
+/// Whether a change with this rendering and classification was reported
+fn has(changes: &[Change], breaking: bool, rendered: &str) -> bool {
+    changes
+        .iter()
+        .any(|change| change.breaking == breaking && change.to_string() == rendered)
+}
+
+/// A path in the temp directory unique to this test process
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rovo-spec-diff-{}-{name}", std::process::id()))
+}
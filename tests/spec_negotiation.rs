@@ -0,0 +1,181 @@
+//! Tests for the single content-negotiated spec endpoint and the 3.0 downgrade
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::OpenApi;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::{rovo, Router};
+use serde::Serialize;
+use serde_json::Value;
+use tower::ServiceExt;
+
+#[derive(Serialize, JsonSchema)]
+struct Address {
+    city: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct User {
+    name: String,
+    nickname: Option<String>,
+    address: Option<Address>,
+}
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+#[rovo]
+async fn get_user() -> impl IntoApiResponse {
+    Json(User {
+        name: "Ada".to_string(),
+        nickname: None,
+        address: None,
+    })
+}
+
+#[tokio::test]
+async fn json_is_the_default() {
+    for accept in [None, Some("*/*"), Some("application/json")] {
+        let response = get_spec(accept).await;
+
+        assert_eq!(response.status(), StatusCode::OK, "Accept: {accept:?}");
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        let spec: Value = serde_json::from_slice(&body(response).await).unwrap();
+        assert!(spec["openapi"].as_str().unwrap().starts_with("3.1"));
+    }
+}
+
+#[tokio::test]
+async fn yaml_when_asked_for() {
+    let response = get_spec(Some("application/yaml")).await;
+
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-yaml"
+    );
+    let vary: Vec<_> = response.headers().get_all(header::VARY).iter().collect();
+    assert!(vary.iter().any(|value| *value == "accept"));
+    let spec: Value = serde_yaml::from_slice(&body(response).await).unwrap();
+    assert_eq!(spec["info"]["title"], "Test API");
+}
+
+#[tokio::test]
+async fn highest_quality_wins() {
+    let response = get_spec(Some("application/json;q=0.5, text/yaml")).await;
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-yaml"
+    );
+
+    let response = get_spec(Some("text/yaml;q=0, application/json")).await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+}
+
+#[tokio::test]
+async fn unsupported_media_types_are_not_acceptable() {
+    for accept in [
+        "text/html",
+        "application/yaml;q=0",
+        "application/vnd.oai.openapi;version=2.0",
+    ] {
+        let response = get_spec(Some(accept)).await;
+        assert_eq!(
+            response.status(),
+            StatusCode::NOT_ACCEPTABLE,
+            "Accept: {accept}"
+        );
+        assert_eq!(
+            response.headers()[header::VARY],
+            "accept",
+            "Accept: {accept}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn openapi_3_0_json_is_downgraded() {
+    let response = get_spec(Some("application/vnd.oai.openapi+json;version=3.0")).await;
+
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/vnd.oai.openapi+json;version=3.0"
+    );
+    let spec: Value = serde_json::from_slice(&body(response).await).unwrap();
+    assert_eq!(spec["openapi"], "3.0.3");
+
+    let user = &spec["components"]["schemas"]["User"]["properties"];
+    assert_eq!(user["name"]["type"], "string");
+    assert_eq!(user["nickname"]["type"], "string");
+    assert_eq!(user["nickname"]["nullable"], true);
+
+    // `Option<Address>` loses its null variant and keeps the reference
+    assert_eq!(user["address"]["nullable"], true);
+    let json = user["address"].to_string();
+    assert!(json.contains("#/components/schemas/Address"), "{json}");
+    assert!(!json.contains("\"null\""), "{json}");
+}
+
+#[tokio::test]
+async fn openapi_3_0_yaml_is_downgraded() {
+    let response = get_spec(Some("application/vnd.oai.openapi;version=3.0")).await;
+
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/vnd.oai.openapi;version=3.0"
+    );
+    let spec: Value = serde_yaml::from_slice(&body(response).await).unwrap();
+    assert_eq!(spec["openapi"], "3.0.3");
+}
+
+#[tokio::test]
+async fn only_the_negotiated_route_is_served() {
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .uri("/openapi.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn app() -> axum::Router {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    Router::new()
+        .route("/users/me", get(get_user))
+        .with_oas_negotiated(api, "/openapi")
+        .finish()
+}
+
+async fn get_spec(accept: Option<&str>) -> Response {
+    let mut request = Request::builder().uri("/openapi");
+    if let Some(accept) = accept {
+        request = request.header(header::ACCEPT, accept);
+    }
+    app()
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body(response: Response) -> Vec<u8> {
+    axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
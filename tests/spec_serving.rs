@@ -0,0 +1,206 @@
+//! Tests for ETags, conditional GET and compression on the spec endpoints
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::OpenApi;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Router};
+use tower::ServiceExt;
+
+/// List items.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - All items
+#[rovo]
+async fn list_items() -> impl IntoApiResponse {
+    Json(Vec::<String>::new())
+}
+
+#[tokio::test]
+async fn spec_has_etag_and_cache_control() {
+    let response = get_spec(app(), "/api.json", &[]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let etag = response.headers()[header::ETAG].to_str().unwrap();
+    assert!(
+        etag.starts_with('"') && etag.ends_with('"'),
+        "strong ETag: {etag}"
+    );
+}
+
+#[tokio::test]
+async fn etag_is_stable_and_differs_per_format() {
+    let first = etag(get_spec(app(), "/api.json", &[]).await);
+    let second = etag(get_spec(app(), "/api.json", &[]).await);
+    let yaml = etag(get_spec(app(), "/api.yaml", &[]).await);
+    let yml = etag(get_spec(app(), "/api.yml", &[]).await);
+
+    assert_eq!(first, second);
+    assert_ne!(first, yaml);
+    assert_eq!(yaml, yml);
+}
+
+#[tokio::test]
+async fn matching_if_none_match_is_not_modified() {
+    let etag = etag(get_spec(app(), "/api.json", &[]).await);
+
+    let response = get_spec(app(), "/api.json", &[(header::IF_NONE_MATCH, &etag)]).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag.as_str());
+    assert!(body(response).await.is_empty());
+
+    // Weak comparison and lists of tags
+    let weak = format!("\"other\", W/{etag}");
+    let response = get_spec(app(), "/api.json", &[(header::IF_NONE_MATCH, &weak)]).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    let response = get_spec(app(), "/api.json", &[(header::IF_NONE_MATCH, "\"other\"")]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cache_control_is_configurable() {
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api())
+        .spec_cache_control("public, max-age=300")
+        .finish();
+
+    let response = get_spec(app, "/api.yaml", &[]).await;
+    assert_eq!(
+        response.headers()[header::CACHE_CONTROL],
+        "public, max-age=300"
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid Cache-Control value")]
+fn invalid_cache_control_panics() {
+    let _ = Router::<()>::new().spec_cache_control("no-cache\n");
+}
+
+#[cfg(not(any(feature = "gzip", feature = "brotli")))]
+#[tokio::test]
+async fn uncompressed_without_compression_features() {
+    let response = get_spec(app(), "/api.json", &[(header::ACCEPT_ENCODING, "gzip, br")]).await;
+
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    assert!(response.headers().get(header::VARY).is_none());
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn serves_gzip_when_accepted() {
+    use std::io::Read;
+
+    let plain = body(get_spec(app(), "/api.json", &[]).await).await;
+    let response = get_spec(app(), "/api.json", &[(header::ACCEPT_ENCODING, "gzip")]).await;
+
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+    assert_eq!(response.headers()[header::VARY], "accept-encoding");
+    let gzip_etag = etag_ref(&response);
+
+    let mut decoded = Vec::new();
+    flate2::read::GzDecoder::new(&body(response).await[..])
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, plain);
+
+    // Each encoding has its own ETag, and revalidates against it
+    let plain_etag = etag(get_spec(app(), "/api.json", &[]).await);
+    assert_ne!(gzip_etag, plain_etag);
+    let response = get_spec(
+        app(),
+        "/api.json",
+        &[
+            (header::ACCEPT_ENCODING, "gzip"),
+            (header::IF_NONE_MATCH, &gzip_etag),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn refused_encodings_are_not_used() {
+    let response = get_spec(app(), "/api.json", &[(header::ACCEPT_ENCODING, "gzip;q=0")]).await;
+
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+}
+
+#[cfg(feature = "brotli")]
+#[tokio::test]
+async fn serves_brotli_when_accepted() {
+    use std::io::Read;
+
+    let plain = body(get_spec(app(), "/api.yaml", &[]).await).await;
+    let response = get_spec(
+        app(),
+        "/api.yaml",
+        &[(header::ACCEPT_ENCODING, "gzip;q=0.5, br")],
+    )
+    .await;
+
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+    let mut decoded = Vec::new();
+    brotli::Decompressor::new(&body(response).await[..], 4096)
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, plain);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
+
+fn app() -> axum::Router {
+    Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api())
+        .finish()
+}
+
+async fn get_spec(
+    app: axum::Router,
+    uri: &str,
+    headers: &[(header::HeaderName, &str)],
+) -> Response {
+    let mut request = Request::builder().uri(uri);
+    for (name, value) in headers {
+        request = request.header(name, *value);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body(response: Response) -> Vec<u8> {
+    axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+fn etag(response: Response) -> String {
+    etag_ref(&response)
+}
+
+fn etag_ref(response: &Response) -> String {
+    response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
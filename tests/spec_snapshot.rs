@@ -0,0 +1,166 @@
+//! Tests for `rovo::testing::assert_spec_snapshot!`
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use rovo::aide::openapi::OpenApi;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::schemars::JsonSchema;
+use rovo::testing::{assert_snapshot, assert_spec_snapshot};
+use rovo::{rovo, Format, Router};
+use serde::Serialize;
+
+#[derive(Serialize, JsonSchema)]
+struct Item {
+    name: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct Order {
+    id: u64,
+}
+
+/// List items.
+///
+/// # Responses
+///
+/// 200: Json<Vec<Item>> - All items
+#[rovo]
+async fn list_items() -> Json<Vec<Item>> {
+    Json(vec![])
+}
+
+/// List orders.
+///
+/// # Responses
+///
+/// 200: Json<Vec<Order>> - All orders
+#[rovo]
+async fn list_orders() -> Json<Vec<Order>> {
+    Json(vec![])
+}
+
+#[test]
+fn matches_checked_in_snapshot() {
+    assert_spec_snapshot!(app(), "tests/snapshots/openapi.json");
+}
+
+#[test]
+fn comparison_ignores_formatting() {
+    let path = temp_path("compact.json");
+    let api = app().to_openapi().unwrap();
+    std::fs::write(&path, serde_json::to_string(&api).unwrap()).unwrap();
+
+    assert_spec_snapshot!(app(), &path);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn yaml_snapshots() {
+    let path = temp_path("spec.yaml");
+    app().write_spec(&path, Format::Yaml).unwrap();
+
+    assert_spec_snapshot!(app(), &path);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mismatch_lists_changes() {
+    let path = temp_path("mismatch.json");
+    app().write_spec(&path, Format::Json).unwrap();
+
+    let mut changed = test_api();
+    changed.info.version = "2.0.0".to_string();
+    let router = Router::<()>::new()
+        .route("/orders", get(list_orders))
+        .with_oas(changed);
+    let message = panic_message(|| assert_snapshot(router, &path, false));
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(message.contains("OpenAPI spec does not match snapshot"));
+    assert!(message.contains("Operations:\n  - GET /items\n  + GET /orders\n"));
+    assert!(message.contains("Schemas:\n  - Item\n  + Order\n"));
+    assert!(message.contains("Other:\n  info/version: \"1.0.0\" -> \"2.0.0\"\n"));
+    assert!(message.contains("help: run with ROVO_UPDATE_SNAPSHOTS=1 to update it"));
+}
+
+#[test]
+fn mismatch_lists_changed_fields() {
+    let path = temp_path("changed.json");
+    app().write_spec(&path, Format::Json).unwrap();
+
+    let mut api = app().to_openapi().unwrap();
+    let operation = api
+        .paths
+        .as_mut()
+        .unwrap()
+        .paths
+        .get_mut("/items")
+        .unwrap()
+        .as_item_mut()
+        .unwrap()
+        .get
+        .as_mut()
+        .unwrap();
+    operation.summary = Some("Every item".to_string());
+    operation.deprecated = true;
+    let message = panic_message(|| assert_snapshot(api, &path, false));
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(message.contains("  ~ GET /items\n"), "{message}");
+    assert!(message.contains("      summary: \"List items.\" -> \"Every item\"\n"));
+    assert!(message.contains("      + deprecated: true\n"));
+    assert!(!message.contains("Schemas:"));
+}
+
+#[test]
+fn missing_snapshot_fails() {
+    let path = temp_path("missing.json");
+
+    let message = panic_message(|| assert_snapshot(app(), &path, false));
+
+    assert!(message.contains("does not exist"));
+    assert!(message.contains("to create it"));
+    assert!(!path.exists());
+}
+
+#[test]
+fn spec_errors_fail() {
+    let router = app().route("/things", get(list_items));
+
+    let message = panic_message(|| assert_spec_snapshot!(router, "tests/snapshots/openapi.json"));
+
+    assert!(message.contains("failed to build the OpenAPI spec"));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn test_api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api.info.version = "1.0.0".to_string();
+    api
+}
+
+fn app() -> Router<()> {
+    Router::new()
+        .route("/items", get(list_items))
+        .with_oas(test_api())
+}
+
+/// A path in the temp directory unique to this test process
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rovo-spec-snapshot-{}-{name}", std::process::id()))
+}
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).expect_err("assertion should fail");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(ToString::to_string))
+        .unwrap_or_default()
+}
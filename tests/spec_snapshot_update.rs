@@ -0,0 +1,69 @@
+//! Tests for updating spec snapshots with `ROVO_UPDATE_SNAPSHOTS`, kept in
+//! their own test binary as they set a process-wide environment variable
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use rovo::aide::openapi::OpenApi;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::testing::{assert_snapshot, assert_spec_snapshot, UPDATE_ENV};
+use rovo::{rovo, Router};
+
+/// List items.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - All items
+#[rovo]
+async fn list_items() -> Json<Vec<String>> {
+    Json(vec![])
+}
+
+#[test]
+fn update_creates_and_rewrites_snapshots() {
+    std::env::set_var(UPDATE_ENV, "1");
+    let path = temp_path("spec.json");
+
+    assert_spec_snapshot!(Router::<()>::new().with_oas(test_api()), &path);
+    let created: OpenApi = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(created.paths.is_none_or(|paths| paths.paths.is_empty()));
+
+    assert_spec_snapshot!(app(), &path);
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(contents.ends_with('\n'));
+    let updated: OpenApi = serde_json::from_str(&contents).unwrap();
+    assert!(updated.paths.unwrap().paths.contains_key("/items"));
+
+    // An explicit flag wins over the environment
+    let missing = temp_path("missing.json");
+    assert!(catch_unwind(AssertUnwindSafe(|| assert_snapshot(app(), &missing, false))).is_err());
+    assert!(!missing.exists());
+
+    std::env::remove_var(UPDATE_ENV);
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn test_api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
+
+fn app() -> Router<()> {
+    Router::new()
+        .route("/items", get(list_items))
+        .with_oas(test_api())
+}
+
+/// A path in the temp directory unique to this test process
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "rovo-spec-snapshot-update-{}-{name}",
+        std::process::id()
+    ))
+}
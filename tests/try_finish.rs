@@ -0,0 +1,171 @@
+//! Tests for `try_finish`, which reports spec problems as `rovo::Error`
+
+use rovo::aide::axum::IntoApiResponse;
+use rovo::aide::openapi::{OpenApi, ReferenceOr, SchemaObject};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Error, Router, SecurityScheme};
+
+/// Get an item.
+///
+/// # Responses
+///
+/// 200: () - The item
+#[rovo]
+async fn get_item() -> impl IntoApiResponse {
+    Json(())
+}
+
+/// Get a secret.
+///
+/// # Responses
+///
+/// 200: () - The secret
+///
+/// # Metadata
+///
+/// @security bearer_auth
+#[rovo]
+async fn get_secret() -> impl IntoApiResponse {
+    Json(())
+}
+
+#[test]
+fn valid_spec_finishes() {
+    let result = Router::<()>::new()
+        .route("/items", get(get_item))
+        .route("/secret", get(get_secret))
+        .security_scheme("bearer_auth", SecurityScheme::bearer_jwt())
+        .with_oas(api())
+        .try_finish();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn router_without_spec_finishes() {
+    let result = Router::<()>::new()
+        .route("/secret", get(get_secret))
+        .try_finish();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn reports_undefined_security_schemes() {
+    let err = Router::<()>::new()
+        .route("/secret", get(get_secret))
+        .with_oas(api())
+        .try_finish()
+        .unwrap_err();
+
+    let Error::UndefinedSecuritySchemes(undefined) = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(undefined.len(), 1);
+    assert_eq!(undefined[0].scheme, "bearer_auth");
+    assert_eq!(
+        undefined[0].operation.as_ref().unwrap().to_string(),
+        "GET /secret"
+    );
+}
+
+#[test]
+fn reports_duplicate_operation_ids() {
+    let err = Router::<()>::new()
+        .route("/items", get(get_item))
+        .route("/things", get(get_item))
+        .with_oas(api())
+        .try_finish()
+        .unwrap_err();
+
+    let Error::DuplicateOperationIds(duplicates) = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].id, "get_item");
+    let operations: Vec<String> = duplicates[0]
+        .operations
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(operations, vec!["GET /items", "GET /things"]);
+    assert!(err
+        .to_string()
+        .contains("`get_item` is used by GET /items, GET /things"));
+}
+
+#[test]
+fn reports_unresolved_references() {
+    let mut api = api();
+    let schema: SchemaObject = serde_json::from_value(serde_json::json!({
+        "json_schema": { "$ref": "#/components/schemas/Missing" },
+        "example": null,
+        "external_docs": null,
+    }))
+    .unwrap();
+    api.components
+        .get_or_insert_with(Default::default)
+        .schemas
+        .insert("Broken".to_string(), schema);
+
+    let err = Router::<()>::new()
+        .route("/items", get(get_item))
+        .with_oas(api)
+        .try_finish()
+        .unwrap_err();
+
+    let Error::UnresolvedReferences(references) = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(
+        references,
+        &vec!["#/components/schemas/Missing".to_string()]
+    );
+}
+
+#[test]
+fn stateful_router_reports_errors() {
+    let result = Router::new()
+        .route("/secret", get(get_secret))
+        .with_oas(api())
+        .with_state(())
+        .try_finish();
+
+    assert!(matches!(result, Err(Error::UndefinedSecuritySchemes(_))));
+}
+
+#[test]
+fn finish_still_allows_duplicate_operation_ids() {
+    let _ = Router::<()>::new()
+        .route("/items", get(get_item))
+        .route("/things", get(get_item))
+        .with_oas(api())
+        .finish();
+}
+
+#[test]
+fn references_in_paths_are_checked() {
+    let mut api = api();
+    api.paths.get_or_insert_with(Default::default).paths.insert(
+        "/external".to_string(),
+        ReferenceOr::Reference {
+            reference: "#/paths/~1missing".to_string(),
+            description: None,
+            summary: None,
+        },
+    );
+
+    let err = Router::<()>::new().with_oas(api).try_finish().unwrap_err();
+    assert!(matches!(err, Error::UnresolvedReferences(_)));
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
@@ -0,0 +1,20 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<String> - User found
+///
+/// # Metadata
+///
+/// @id getUser
+#[rovo(id = "fetchUser")]
+async fn get_user() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#![deny(deprecated)]
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::extract::Path;
+use rovo::http::{HeaderMap, StatusCode};
+use rovo::rovo;
+
+/// Get a user.
+///
+/// # Response
+///
+/// 200: () - User found
+#[rovo]
+async fn get_user(Path(id): Path<u64>) -> impl IntoApiResponse {
+    let _ = id;
+    StatusCode::OK
+}
+
+/// Delete a user.
+///
+/// # Responses
+///
+/// 204: () - User deleted
+///
+/// # Notes
+///
+/// Deletion is permanent.
+#[rovo(allow(unknown_sections, undocumented_path_params))]
+async fn delete_user(Path(id): Path<u64>) -> StatusCode {
+    let _ = id;
+    StatusCode::NO_CONTENT
+}
+
+/// Get the current tenant.
+///
+/// # Responses
+///
+/// 200: () - The tenant
+#[rovo]
+async fn get_tenant(headers: HeaderMap) -> StatusCode {
+    let _ = headers;
+    StatusCode::OK
+}
+
+/// Get the current locale.
+///
+/// # Responses
+///
+/// 200: () - The locale
+#[rovo(allow(undocumented_headers))]
+async fn get_locale(headers: HeaderMap) -> StatusCode {
+    let _ = headers;
+    StatusCode::OK
+}
+
+fn main() {}
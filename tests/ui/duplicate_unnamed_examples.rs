@@ -0,0 +1,21 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// List items.
+///
+/// # Responses
+///
+/// 200: Json<Vec<u32>> - Items
+///
+/// # Examples
+///
+/// 200: vec![]
+/// 200: vec![1, 2, 3]
+#[rovo]
+async fn list() -> impl IntoApiResponse {
+    Json(Vec::<u32>::new())
+}
+
+fn main() {}
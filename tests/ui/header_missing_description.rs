@@ -0,0 +1,21 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::http::HeaderMap;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get the current tenant.
+///
+/// # Headers
+///
+/// X-Tenant-Id: String (required)
+///
+/// # Responses
+///
+/// 200: Json<String> - The tenant
+#[rovo]
+async fn get_tenant(_headers: HeaderMap) -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
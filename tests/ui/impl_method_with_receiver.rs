@@ -0,0 +1,21 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+struct Users;
+
+#[rovo]
+impl Users {
+    /// Get a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - User found
+    #[rovo]
+    async fn get_user(&self) -> impl IntoApiResponse {
+        Json(String::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 99999: Json<String> - User found
+/// 404: () - User not found
+///
+/// # Examples
+///
+/// 404: ()
+/// 404: ()
+///
+/// # Metadata
+///
+/// @tg users
+#[rovo]
+async fn get_user() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::extract::Query;
+use rovo::response::Json;
+use rovo::rovo;
+use rovo::schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+struct SearchParams {
+    q: String,
+}
+
+/// Search items.
+///
+/// # Query Parameters
+///
+/// term: The search term
+///
+/// # Responses
+///
+/// 200: Json<String> - Search results
+#[rovo]
+async fn search(Query(SearchParams { q }): Query<SearchParams>) -> impl IntoApiResponse {
+    Json(q)
+}
+
+fn main() {}
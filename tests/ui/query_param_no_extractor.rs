@@ -0,0 +1,20 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// List items.
+///
+/// # Query Parameters
+///
+/// page: Page number
+///
+/// # Responses
+///
+/// 200: Json<String> - Items
+#[rovo]
+async fn list() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
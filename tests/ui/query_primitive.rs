@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::extract::Query;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Search items.
+///
+/// # Responses
+///
+/// 200: Json<String> - Search results
+#[rovo]
+async fn search(Query(q): Query<String>) -> impl IntoApiResponse {
+    Json(q)
+}
+
+fn main() {}
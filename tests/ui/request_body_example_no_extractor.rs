@@ -0,0 +1,21 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Create a todo.
+///
+/// # Request Body
+///
+/// The todo item to create
+/// Example: "Buy milk"
+///
+/// # Responses
+///
+/// 200: Json<String> - Created
+#[rovo]
+async fn create_todo() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
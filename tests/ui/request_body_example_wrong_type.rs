@@ -0,0 +1,26 @@
+use rovo::response::Json;
+use rovo::rovo;
+use rovo::schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct CreateTodo {
+    title: String,
+}
+
+/// Create a todo.
+///
+/// # Request Body
+///
+/// The todo item to create
+/// Example: "Buy milk"
+///
+/// # Responses
+///
+/// 200: Json<String> - Created
+#[rovo]
+async fn create_todo(Json(todo): Json<CreateTodo>) -> Json<String> {
+    Json(todo.title)
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Create an item.
+///
+/// # Responses
+///
+///   header Location: String - URL of the created resource
+/// 201: Json<String> - Item created
+#[rovo]
+async fn create_item() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<String> - User found
+#[rovo(id_style = "kebab")]
+async fn get_user() -> impl IntoApiResponse {
+    Json(String::new())
+}
+
+fn main() {}
@@ -0,0 +1,132 @@
+//! Tests for building and writing the spec without serving it
+
+use std::path::PathBuf;
+
+use rovo::aide::openapi::OpenApi;
+use rovo::extract::State;
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Error, Format, Router};
+
+/// State the handlers need, which is never constructed here
+#[derive(Clone)]
+struct Database {
+    _url: String,
+}
+
+/// List items.
+///
+/// # Responses
+///
+/// 200: Json<Vec<String>> - All items
+#[rovo]
+async fn list_items(State(_db): State<Database>) -> Json<Vec<String>> {
+    Json(vec![])
+}
+
+#[test]
+fn to_openapi_does_not_need_state() {
+    let api = app().to_openapi().unwrap();
+
+    assert_eq!(api.info.title, "Test API");
+    let paths = api.paths.unwrap();
+    let item = paths.paths["/items"].as_item().unwrap();
+    assert_eq!(
+        item.get.as_ref().unwrap().operation_id.as_deref(),
+        Some("list_items")
+    );
+}
+
+#[test]
+fn to_openapi_without_with_oas_uses_an_empty_spec() {
+    let api = Router::<Database>::new()
+        .route("/items", get(list_items))
+        .to_openapi()
+        .unwrap();
+
+    assert!(api.paths.unwrap().paths.contains_key("/items"));
+}
+
+#[test]
+fn to_openapi_runs_the_checks() {
+    let err = app()
+        .route("/things", get(list_items))
+        .to_openapi()
+        .unwrap_err();
+
+    assert!(matches!(err, Error::DuplicateOperationIds(_)));
+}
+
+#[test]
+fn stateful_router_to_openapi() {
+    let api = Router::new()
+        .nest(
+            "/api",
+            app().with_state(Database {
+                _url: String::new(),
+            }),
+        )
+        .with_oas(test_api())
+        .with_state(())
+        .to_openapi()
+        .unwrap();
+
+    assert!(api.paths.unwrap().paths.contains_key("/api/items"));
+}
+
+#[test]
+fn writes_json() {
+    let path = temp_path("spec.json");
+    app().write_spec(&path, Format::Json).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(contents.ends_with('\n'));
+    assert!(contents.contains("\n  \"openapi\""), "pretty-printed");
+    let api: OpenApi = serde_json::from_str(&contents).unwrap();
+    assert_eq!(api.info.title, "Test API");
+}
+
+#[test]
+fn writes_yaml() {
+    let path = temp_path("spec.yaml");
+    app().write_spec(&path, Format::Yaml).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let api: OpenApi = serde_yaml::from_str(&contents).unwrap();
+    assert!(api.paths.unwrap().paths.contains_key("/items"));
+}
+
+#[test]
+fn reports_write_errors() {
+    let path = temp_path("missing-dir").join("spec.json");
+    let err = app().write_spec(&path, Format::Json).unwrap_err();
+
+    let Error::Write { path: failed, .. } = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(failed, &path);
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+// =============================================================================
+// Helper functions
+// =============================================================================
+
+fn test_api() -> OpenApi {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api
+}
+
+fn app() -> Router<Database> {
+    Router::new()
+        .route("/items", get(list_items))
+        .with_oas(test_api())
+}
+
+/// A path in the temp directory unique to this test process
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rovo-write-spec-{}-{name}", std::process::id()))
+}